@@ -2,7 +2,7 @@ use super::composition;
 use super::elements;
 
 #[allow(clippy::large_enum_variant)]
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "snake_case", tag = "type")]
 pub enum MessageBlock {
     Actions(Actions),
@@ -10,11 +10,12 @@ pub enum MessageBlock {
     Divider(Divider),
     Header(Header),
     Image(Image),
+    RichText(RichText),
     Section(Section),
 }
 
 #[allow(clippy::large_enum_variant)]
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "snake_case", tag = "type")]
 pub enum ModalBlock {
     Actions(Actions),
@@ -23,11 +24,12 @@ pub enum ModalBlock {
     Header(Header),
     Image(Image),
     Input(Input),
+    RichText(RichText),
     Section(Section),
 }
 
 #[allow(clippy::large_enum_variant)]
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "snake_case", tag = "type")]
 pub enum HomeTabBlock {
     Actions(Actions),
@@ -35,94 +37,143 @@ pub enum HomeTabBlock {
     Divider(Divider),
     Header(Header),
     Image(Image),
+    RichText(RichText),
     Section(Section),
 }
 
 #[impl_for(MessageBlock, ModalBlock, HomeTabBlock)]
-#[derive(TypedBuilder, Serialize)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
 pub struct Actions {
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(into, strip_option), default)]
-    block_id: Option<String>,
+    pub block_id: Option<String>,
     #[builder(setter(into))]
-    elements: Vec<elements::ActionElements>,
+    pub elements: Vec<elements::ActionElements>,
 }
 
 #[impl_for(MessageBlock, ModalBlock, HomeTabBlock)]
-#[derive(TypedBuilder, Serialize)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
 pub struct Context {
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(into, strip_option), default)]
-    block_id: Option<String>,
+    pub block_id: Option<String>,
     #[builder(setter(into))]
-    elements: Vec<elements::ContextElements>,
+    pub elements: Vec<elements::ContextElements>,
 }
 
 #[impl_for(MessageBlock, ModalBlock, HomeTabBlock)]
-#[derive(TypedBuilder, Serialize)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
 pub struct Divider {
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(into, strip_option), default)]
-    block_id: Option<String>,
+    pub block_id: Option<String>,
 }
 
 // TODO: Add `Files`, maybe. It can't actually be created by an end user (only
 // added by Slack), so it's not helpful unless we deserialize.
 
 #[impl_for(MessageBlock, ModalBlock, HomeTabBlock)]
-#[derive(TypedBuilder, Serialize)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
 pub struct Header {
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(into, strip_option), default)]
-    block_id: Option<String>,
+    pub block_id: Option<String>,
 
     #[builder(setter(into))]
-    text: composition::PlainText,
+    pub text: composition::PlainText,
 }
 
 #[impl_for(MessageBlock, ModalBlock, HomeTabBlock)]
-#[derive(TypedBuilder, Serialize)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
 pub struct Image {
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(into, strip_option), default)]
-    block_id: Option<String>,
+    pub block_id: Option<String>,
 
     #[builder(setter(into))]
-    image_url: String,
+    pub image_url: String,
 
     #[builder(setter(into))]
-    alt_text: String,
+    pub alt_text: String,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(into, strip_option), default)]
-    title: Option<composition::PlainText>,
+    pub title: Option<composition::PlainText>,
 }
 
 #[impl_for(ModalBlock)]
-#[derive(TypedBuilder, Serialize)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
 pub struct Input {
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(into, strip_option), default)]
-    block_id: Option<String>,
+    pub block_id: Option<String>,
 
     #[builder(setter(into))]
-    label: composition::PlainText,
+    pub label: composition::PlainText,
 
     #[builder(setter(into))]
-    element: elements::InputElements,
+    pub element: elements::InputElements,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(into, strip_option), default)]
-    hint: Option<composition::PlainText>,
+    pub hint: Option<composition::PlainText>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(into, strip_option), default)]
-    optional: Option<bool>,
+    pub optional: Option<bool>,
 }
 
+/// [https://api.slack.com/reference/block-kit/blocks#rich_text](https://api.slack.com/reference/block-kit/blocks#rich_text)
+///
+/// This is what Slack turns messages typed into the composer into. The
+/// elements aren't modelled yet, so they're left as raw JSON.
+///
+/// ```
+/// # use slack_lib::block_kit::blocks::*;
+/// let block: MessageBlock = serde_json::from_value(serde_json::json!({
+///   "type": "rich_text",
+///   "block_id": "2ZN",
+///   "elements": [
+///     {
+///       "type": "rich_text_section",
+///       "elements": [{ "type": "text", "text": "Hello there" }]
+///     }
+///   ]
+/// }))
+/// .unwrap();
+///
+/// match block {
+///     MessageBlock::RichText(rich_text) => assert_eq!(rich_text.elements.len(), 1),
+///     _ => panic!("expected a rich_text block"),
+/// }
+/// ```
 #[impl_for(MessageBlock, ModalBlock, HomeTabBlock)]
-#[derive(TypedBuilder, Serialize)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
+pub struct RichText {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub block_id: Option<String>,
+
+    pub elements: Vec<serde_json::Value>,
+}
+
+#[impl_for(MessageBlock, ModalBlock, HomeTabBlock)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
 pub struct Section {
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(into, strip_option), default)]
-    block_id: Option<String>,
+    pub block_id: Option<String>,
 
-    #[builder(setter(into))]
-    text: composition::Text,
+    /// Required unless there are `fields`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub text: Option<composition::Text>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    fields: Option<Vec<composition::Text>>,
+    pub fields: Option<Vec<composition::Text>>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(into, strip_option), default)]
-    accessory: Option<elements::SectionElements>,
+    pub accessory: Option<elements::SectionElements>,
 }
@@ -1,10 +1,15 @@
-#[derive(Serialize)]
+#[derive(Serialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum Text {
     PlainText(PlainText),
     Markdown(Markdown),
 }
 
+deserialize_by_type!(Text {
+    "plain_text" => PlainText,
+    "mrkdwn" => Markdown,
+});
+
 /// [https://api.slack.com/reference/block-kit/composition-objects#text](https://api.slack.com/reference/block-kit/composition-objects#text)
 ///
 /// Note that for the most part (unless you want to set `emoji` away from it's
@@ -41,16 +46,16 @@ pub enum Text {
 /// assert_json_eq!(&expected, output);
 /// ```
 #[impl_for(Text)]
-#[derive(TypedBuilder, Serialize)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
 pub struct PlainText {
     #[serde(rename = "type")]
     #[builder(setter(skip), default = String::from("plain_text"))]
     block_type: String,
     #[builder(setter(into))]
-    text: String,
+    pub text: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default = Option::None)]
-    emoji: Option<bool>,
+    pub emoji: Option<bool>,
 }
 
 impl From<&str> for PlainText {
@@ -95,16 +100,16 @@ impl From<&str> for PlainText {
 /// assert_json_eq!(&expected, output);
 /// ```
 #[impl_for(Text)]
-#[derive(TypedBuilder, Serialize)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
 pub struct Markdown {
     #[serde(rename = "type")]
     #[builder(setter(skip), default = String::from("mrkdwn"))]
     block_type: String,
     #[builder(setter(into))]
-    text: String,
+    pub text: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default = Option::None)]
-    verbatim: Option<bool>,
+    pub verbatim: Option<bool>,
 }
 
 impl From<&str> for Markdown {
@@ -113,7 +118,8 @@ impl From<&str> for Markdown {
     }
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
 pub enum ConfirmationStyle {
     Primary,
     Danger,
@@ -167,28 +173,54 @@ pub enum ConfirmationStyle {
 /// let output = serde_json::to_value(&confirm).unwrap();
 /// assert_json_eq!(output, expected);
 /// ```
-#[derive(TypedBuilder, Serialize)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
 pub struct Confirmation {
     #[builder(setter(into))]
-    title: PlainText,
+    pub title: PlainText,
     #[builder(setter(into))]
-    text: Text,
+    pub text: Text,
     #[builder(setter(into))]
-    confirm: PlainText,
+    pub confirm: PlainText,
     #[builder(setter(into))]
-    deny: PlainText,
+    pub deny: PlainText,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    style: Option<ConfirmationStyle>,
+    pub style: Option<ConfirmationStyle>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum OptionItem {
     PlainTextOptionItem(PlainTextOptionItem),
     MarkdownOptionItem(MarkdownOptionItem),
 }
 
+// The only difference between the two is the type of their `text`
+impl<'de> serde::Deserialize<'de> for OptionItem {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let is_markdown = value
+            .pointer("/text/type")
+            .and_then(serde_json::Value::as_str)
+            == Some("mrkdwn");
+
+        if is_markdown {
+            serde_json::from_value(value)
+                .map(OptionItem::MarkdownOptionItem)
+                .map_err(D::Error::custom)
+        } else {
+            serde_json::from_value(value)
+                .map(OptionItem::PlainTextOptionItem)
+                .map_err(D::Error::custom)
+        }
+    }
+}
+
 /// [https://api.slack.com/reference/block-kit/composition-objects#option](https://api.slack.com/reference/block-kit/composition-objects#option)
 ///
 /// Create a builder with [`PlainTextOptionItem::builder()`]
@@ -223,15 +255,15 @@ pub enum OptionItem {
 /// ```
 // Used in select and multi-select
 #[impl_for(OptionItem)]
-#[derive(TypedBuilder, Serialize)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
 pub struct PlainTextOptionItem {
     #[builder(setter(into))]
-    text: PlainText,
+    pub text: PlainText,
     #[builder(setter(into))]
-    value: String,
+    pub value: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(into, strip_option), default)]
-    description: Option<PlainText>,
+    pub description: Option<PlainText>,
 }
 
 /// [https://api.slack.com/reference/block-kit/composition-objects#option](https://api.slack.com/reference/block-kit/composition-objects#option)
@@ -269,15 +301,15 @@ pub struct PlainTextOptionItem {
 /// assert_json_eq!(expected, output);
 /// ```
 #[impl_for(OptionItem)]
-#[derive(TypedBuilder, Serialize)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
 pub struct MarkdownOptionItem {
     #[builder(setter(into))]
-    text: Markdown,
+    pub text: Markdown,
     #[builder(setter(into))]
-    value: String,
+    pub value: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(into, strip_option), default)]
-    description: Option<PlainText>,
+    pub description: Option<PlainText>,
 }
 
 /// [https://api.slack.com/reference/block-kit/composition-objects#option](https://api.slack.com/reference/block-kit/composition-objects#option)
@@ -318,18 +350,18 @@ pub struct MarkdownOptionItem {
 /// let output = serde_json::to_value(&option).unwrap();
 /// assert_json_eq!(expected, output);
 /// ```
-#[derive(TypedBuilder, Serialize)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
 pub struct OverflowOptionItem {
     #[builder(setter(into))]
-    text: PlainText,
+    pub text: PlainText,
     #[builder(setter(into))]
-    value: String,
+    pub value: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(into, strip_option), default)]
-    description: Option<PlainText>,
+    pub description: Option<PlainText>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(into, strip_option), default)]
-    url: Option<String>,
+    pub url: Option<String>,
 }
 
 /// [https://api.slack.com/reference/block-kit/composition-objects#option_group](https://api.slack.com/reference/block-kit/composition-objects#option_group)
@@ -430,14 +462,14 @@ pub struct OverflowOptionItem {
 /// let output = serde_json::to_value(&option).unwrap();
 /// assert_json_eq!(expected, output);
 /// ```
-#[derive(TypedBuilder, Serialize)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
 pub struct OptionGroup {
     #[builder(setter(into))]
-    label: PlainText,
-    options: Vec<PlainTextOptionItem>,
+    pub label: PlainText,
+    pub options: Vec<PlainTextOptionItem>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum FilterInclusions {
     IM,
@@ -481,17 +513,17 @@ pub enum FilterInclusions {
 /// let output = serde_json::to_value(&filter).unwrap();
 /// assert_json_eq!(expected, output);
 /// ```
-#[derive(TypedBuilder, Serialize)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
 #[builder(builder_method_doc = "")]
 pub struct Filter {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
     /// Included
-    include: Option<Vec<FilterInclusions>>,
+    pub include: Option<Vec<FilterInclusions>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    exclude_external_shared_channels: Option<bool>,
+    pub exclude_external_shared_channels: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    exclude_bot_users: Option<bool>,
+    pub exclude_bot_users: Option<bool>,
 }
@@ -1,6 +1,6 @@
 use super::composition;
 
-#[derive(Serialize)]
+#[derive(Serialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum SectionElements {
     Button(Button),
@@ -22,7 +22,27 @@ pub enum SectionElements {
     ChannelsSelect(ChannelsSelect),
 }
 
-#[derive(Serialize)]
+deserialize_by_type!(SectionElements {
+    "button" => Button,
+    "checkboxes" => Checkboxes,
+    "datepicker" => Datepicker,
+    "image" => Image,
+    "multi_static_select" => MultiStaticSelect,
+    "multi_external_select" => MultiExternalSelect,
+    "multi_users_select" => MultiUsersSelect,
+    "multi_conversations_select" => MultiConversationsSelect,
+    "multi_channels_select" => MultiChannelsSelect,
+    "overflow" => Overflow,
+    "plain_text_input" => PlainTextInput,
+    "radio_buttons" => RadioButtons,
+    "static_select" => StaticSelect,
+    "external_select" => ExternalSelect,
+    "users_select" => UsersSelect,
+    "conversations_select" => ConversationsSelect,
+    "channels_select" => ChannelsSelect,
+});
+
+#[derive(Serialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum ActionElements {
     Button(Button),
@@ -38,7 +58,21 @@ pub enum ActionElements {
     ChannelsSelect(ChannelsSelect),
 }
 
-#[derive(Serialize)]
+deserialize_by_type!(ActionElements {
+    "button" => Button,
+    "checkboxes" => Checkboxes,
+    "datepicker" => Datepicker,
+    "overflow" => Overflow,
+    "plain_text_input" => PlainTextInput,
+    "radio_buttons" => RadioButtons,
+    "static_select" => StaticSelect,
+    "external_select" => ExternalSelect,
+    "users_select" => UsersSelect,
+    "conversations_select" => ConversationsSelect,
+    "channels_select" => ChannelsSelect,
+});
+
+#[derive(Serialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum InputElements {
     Button(Button),
@@ -58,39 +92,54 @@ pub enum InputElements {
     ChannelsSelect(ChannelsSelect),
 }
 
-#[derive(Serialize)]
+deserialize_by_type!(InputElements {
+    "button" => Button,
+    "checkboxes" => Checkboxes,
+    "datepicker" => Datepicker,
+    "multi_static_select" => MultiStaticSelect,
+    "multi_external_select" => MultiExternalSelect,
+    "multi_users_select" => MultiUsersSelect,
+    "multi_conversations_select" => MultiConversationsSelect,
+    "multi_channels_select" => MultiChannelsSelect,
+    "plain_text_input" => PlainTextInput,
+    "radio_buttons" => RadioButtons,
+    "static_select" => StaticSelect,
+    "external_select" => ExternalSelect,
+    "users_select" => UsersSelect,
+    "conversations_select" => ConversationsSelect,
+    "channels_select" => ChannelsSelect,
+});
+
+#[derive(Serialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum ContextElements {
     Image(Image),
     Text(composition::Text),
 }
 
-#[derive(Serialize)]
+deserialize_by_type!(ContextElements {
+    "image" => Image,
+    "plain_text" | "mrkdwn" => Text,
+});
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum ButtonStyle {
     Primary,
     Danger,
+    #[default]
     Default,
 }
 
 impl ButtonStyle {
     fn should_skip(item: &Option<ButtonStyle>) -> bool {
         match item {
-            Some(style) => match style {
-                ButtonStyle::Default => true,
-                _ => false,
-            },
+            Some(style) => matches!(style, ButtonStyle::Default),
             None => true,
         }
     }
 }
 
-impl Default for ButtonStyle {
-    fn default() -> Self {
-        Self::Default
-    }
-}
-
 /// [https://api.slack.com/reference/block-kit/block-elements#button](https://api.slack.com/reference/block-kit/block-elements#button)
 ///
 /// A regular interactive button
@@ -176,33 +225,33 @@ impl Default for ButtonStyle {
 /// assert_json_eq!(expected, output);
 /// ```
 #[impl_for(SectionElements, ActionElements)]
-#[derive(TypedBuilder, Serialize)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
 pub struct Button {
     #[serde(rename = "type")]
     #[builder(setter(skip), default = String::from("button"))]
     block_type: String,
 
     #[builder(setter(into))]
-    text: composition::PlainText,
+    pub text: composition::PlainText,
 
     #[builder(setter(into))]
-    action_id: String,
+    pub action_id: String,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(into, strip_option), default)]
-    url: Option<String>,
+    pub url: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(into, strip_option), default)]
-    value: Option<String>,
+    pub value: Option<String>,
 
     #[serde(skip_serializing_if = "ButtonStyle::should_skip")]
     #[builder(setter(strip_option), default)]
-    style: Option<ButtonStyle>,
+    pub style: Option<ButtonStyle>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    confirm: Option<composition::Confirmation>,
+    pub confirm: Option<composition::Confirmation>,
 }
 
 /// [https://api.slack.com/reference/block-kit/block-elements#checkboxes](https://api.slack.com/reference/block-kit/block-elements#checkboxes)
@@ -272,24 +321,24 @@ pub struct Button {
 /// assert_json_eq!(expected, output);
 /// ```
 #[impl_for(InputElements, SectionElements, ActionElements)]
-#[derive(TypedBuilder, Serialize)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
 pub struct Checkboxes {
     #[serde(rename = "type")]
     #[builder(setter(skip), default = String::from("checkboxes"))]
     block_type: String,
 
     #[builder(setter(into))]
-    action_id: String,
+    pub action_id: String,
 
-    options: Vec<composition::OptionItem>,
+    pub options: Vec<composition::OptionItem>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    initial_options: Option<Vec<composition::OptionItem>>,
+    pub initial_options: Option<Vec<composition::OptionItem>>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    confirm: Option<composition::Confirmation>,
+    pub confirm: Option<composition::Confirmation>,
 }
 
 /// [https://api.slack.com/reference/block-kit/block-elements#datepicker](https://api.slack.com/reference/block-kit/block-elements#datepicker)
@@ -320,26 +369,26 @@ pub struct Checkboxes {
 /// assert_json_eq!(expected, output);
 /// ```
 #[impl_for(InputElements, SectionElements, ActionElements)]
-#[derive(TypedBuilder, Serialize)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
 pub struct Datepicker {
     #[serde(rename = "type")]
     #[builder(setter(skip), default = String::from("datepicker"))]
     block_type: String,
 
     #[builder(setter(into))]
-    action_id: String,
+    pub action_id: String,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option, into), default)]
-    placeholder: Option<composition::PlainText>,
+    pub placeholder: Option<composition::PlainText>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option, into), default)]
-    initial_date: Option<String>,
+    pub initial_date: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    confirm: Option<composition::Confirmation>,
+    pub confirm: Option<composition::Confirmation>,
 }
 
 /// [https://api.slack.com/reference/block-kit/block-elements#image](https://api.slack.com/reference/block-kit/block-elements#image)
@@ -365,16 +414,16 @@ pub struct Datepicker {
 /// assert_json_eq!(expected, output);
 /// ```
 #[impl_for(SectionElements, ContextElements)]
-#[derive(TypedBuilder, Serialize)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
 pub struct Image {
     #[serde(rename = "type")]
     #[builder(setter(skip), default = String::from("image"))]
     block_type: String,
 
     #[builder(setter(into))]
-    image_url: String,
+    pub image_url: String,
     #[builder(setter(into))]
-    alt_text: String,
+    pub alt_text: String,
 }
 
 /// [https://api.slack.com/reference/block-kit/block-elements#static_multi_select](https://api.slack.com/reference/block-kit/block-elements#static_multi_select)
@@ -440,37 +489,37 @@ pub struct Image {
 /// assert_json_eq!(expected, output);
 /// ```
 #[impl_for(SectionElements, InputElements)]
-#[derive(TypedBuilder, Serialize)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
 pub struct MultiStaticSelect {
     #[serde(rename = "type")]
     #[builder(setter(skip), default = String::from("multi_static_select"))]
     block_type: String,
 
     #[builder(setter(into))]
-    placeholder: composition::PlainText,
+    pub placeholder: composition::PlainText,
 
     #[builder(setter(into))]
-    action_id: String,
+    pub action_id: String,
 
     // Technically, one of the following two is required, but I can't model that
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    options: Option<Vec<composition::PlainTextOptionItem>>,
+    pub options: Option<Vec<composition::PlainTextOptionItem>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    option_groups: Option<Vec<composition::OptionGroup>>,
+    pub option_groups: Option<Vec<composition::OptionGroup>>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    initial_options: Option<Vec<composition::PlainTextOptionItem>>,
+    pub initial_options: Option<Vec<composition::PlainTextOptionItem>>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    confirm: Option<composition::Confirmation>,
+    pub confirm: Option<composition::Confirmation>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    max_selected_items: Option<i32>,
+    pub max_selected_items: Option<i32>,
 }
 
 /// [https://api.slack.com/reference/block-kit/block-elements#external_multi_select](https://api.slack.com/reference/block-kit/block-elements#external_multi_select)
@@ -501,33 +550,33 @@ pub struct MultiStaticSelect {
 /// assert_json_eq!(expected, output);
 /// ```
 #[impl_for(SectionElements, InputElements)]
-#[derive(TypedBuilder, Serialize)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
 pub struct MultiExternalSelect {
     #[serde(rename = "type")]
     #[builder(setter(skip), default = String::from("multi_external_select"))]
     block_type: String,
 
     #[builder(setter(into))]
-    placeholder: composition::PlainText,
+    pub placeholder: composition::PlainText,
 
     #[builder(setter(into))]
-    action_id: String,
+    pub action_id: String,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    min_query_length: Option<i32>,
+    pub min_query_length: Option<i32>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    initial_options: Option<Vec<composition::PlainTextOptionItem>>,
+    pub initial_options: Option<Vec<composition::PlainTextOptionItem>>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    confirm: Option<composition::Confirmation>,
+    pub confirm: Option<composition::Confirmation>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    max_selected_items: Option<i32>,
+    pub max_selected_items: Option<i32>,
 }
 
 /// [https://api.slack.com/reference/block-kit/block-elements#users_multi_select](https://api.slack.com/reference/block-kit/block-elements#users_multi_select)
@@ -556,29 +605,29 @@ pub struct MultiExternalSelect {
 /// assert_json_eq!(expected, output);
 /// ```
 #[impl_for(SectionElements, InputElements)]
-#[derive(TypedBuilder, Serialize)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
 pub struct MultiUsersSelect {
     #[serde(rename = "type")]
     #[builder(setter(skip), default = String::from("multi_users_select"))]
     block_type: String,
 
     #[builder(setter(into))]
-    placeholder: composition::PlainText,
+    pub placeholder: composition::PlainText,
 
     #[builder(setter(into))]
-    action_id: String,
+    pub action_id: String,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    initial_users: Option<Vec<String>>,
+    pub initial_users: Option<Vec<String>>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    confirm: Option<composition::Confirmation>,
+    pub confirm: Option<composition::Confirmation>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    max_selected_items: Option<i32>,
+    pub max_selected_items: Option<i32>,
 }
 
 /// [https://api.slack.com/reference/block-kit/block-elements#conversation_multi_select](https://api.slack.com/reference/block-kit/block-elements#conversation_multi_select)
@@ -607,37 +656,37 @@ pub struct MultiUsersSelect {
 /// assert_json_eq!(expected, output);
 /// ```
 #[impl_for(SectionElements, InputElements)]
-#[derive(TypedBuilder, Serialize)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
 pub struct MultiConversationsSelect {
     #[serde(rename = "type")]
     #[builder(setter(skip), default = String::from("multi_conversations_select"))]
     block_type: String,
 
     #[builder(setter(into))]
-    placeholder: composition::PlainText,
+    pub placeholder: composition::PlainText,
 
     #[builder(setter(into))]
-    action_id: String,
+    pub action_id: String,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    initial_conversations: Option<Vec<String>>,
+    pub initial_conversations: Option<Vec<String>>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    default_to_current_conversation: Option<bool>,
+    pub default_to_current_conversation: Option<bool>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    confirm: Option<composition::Confirmation>,
+    pub confirm: Option<composition::Confirmation>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    max_selected_items: Option<i32>,
+    pub max_selected_items: Option<i32>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    filter: Option<composition::Filter>,
+    pub filter: Option<composition::Filter>,
 }
 
 /// [https://api.slack.com/reference/block-kit/block-elements#channel_multi_select](https://api.slack.com/reference/block-kit/block-elements#channel_multi_select)
@@ -666,29 +715,29 @@ pub struct MultiConversationsSelect {
 /// assert_json_eq!(expected, output);
 /// ```
 #[impl_for(SectionElements, InputElements)]
-#[derive(TypedBuilder, Serialize)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
 pub struct MultiChannelsSelect {
     #[serde(rename = "type")]
     #[builder(setter(skip), default = String::from("multi_channels_select"))]
     block_type: String,
 
     #[builder(setter(into))]
-    placeholder: composition::PlainText,
+    pub placeholder: composition::PlainText,
 
     #[builder(setter(into))]
-    action_id: String,
+    pub action_id: String,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    initial_channels: Option<Vec<String>>,
+    pub initial_channels: Option<Vec<String>>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    confirm: Option<composition::Confirmation>,
+    pub confirm: Option<composition::Confirmation>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    max_selected_items: Option<i32>,
+    pub max_selected_items: Option<i32>,
 }
 
 /// [https://api.slack.com/reference/block-kit/block-elements#overflow](https://api.slack.com/reference/block-kit/block-elements#overflow)
@@ -771,20 +820,20 @@ pub struct MultiChannelsSelect {
 /// assert_json_eq!(expected, output);
 /// ```
 #[impl_for(SectionElements, ActionElements)]
-#[derive(TypedBuilder, Serialize)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
 pub struct Overflow {
     #[serde(rename = "type")]
     #[builder(setter(skip), default = String::from("overflow"))]
     block_type: String,
 
     #[builder(setter(into))]
-    action_id: String,
+    pub action_id: String,
 
-    options: Vec<composition::OverflowOptionItem>,
+    pub options: Vec<composition::OverflowOptionItem>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    confirm: Option<composition::Confirmation>,
+    pub confirm: Option<composition::Confirmation>,
 }
 
 /// [https://api.slack.com/reference/block-kit/block-elements#input](https://api.slack.com/reference/block-kit/block-elements#input)
@@ -813,34 +862,34 @@ pub struct Overflow {
 /// assert_json_eq!(expected, output);
 /// ```
 #[impl_for(SectionElements, ActionElements, InputElements)]
-#[derive(TypedBuilder, Serialize)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
 pub struct PlainTextInput {
     #[serde(rename = "type")]
     #[builder(setter(skip), default = String::from("plain_text_input"))]
     block_type: String,
 
     #[builder(setter(into))]
-    action_id: String,
+    pub action_id: String,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(into, strip_option), default)]
-    placeholder: Option<composition::PlainText>,
+    pub placeholder: Option<composition::PlainText>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(into, strip_option), default)]
-    initial_value: Option<String>,
+    pub initial_value: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    multiline: Option<bool>,
+    pub multiline: Option<bool>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    min_length: Option<i32>,
+    pub min_length: Option<i32>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    max_length: Option<i32>,
+    pub max_length: Option<i32>,
 }
 
 /// [https://api.slack.com/reference/block-kit/block-elements#radio](https://api.slack.com/reference/block-kit/block-elements#radio)
@@ -906,23 +955,23 @@ pub struct PlainTextInput {
 /// assert_json_eq!(expected, output);
 /// ```
 #[impl_for(SectionElements, ActionElements, InputElements)]
-#[derive(TypedBuilder, Serialize)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
 pub struct RadioButtons {
     #[serde(rename = "type")]
     #[builder(setter(skip), default = String::from("radio_buttons"))]
     block_type: String,
 
     #[builder(setter(into))]
-    action_id: String,
+    pub action_id: String,
 
-    options: Vec<composition::OptionItem>,
+    pub options: Vec<composition::OptionItem>,
 
     #[builder(setter(strip_option))]
-    initial_option: Option<composition::OptionItem>,
+    pub initial_option: Option<composition::OptionItem>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    confirm: Option<composition::Confirmation>,
+    pub confirm: Option<composition::Confirmation>,
 }
 
 /// [https://api.slack.com/reference/block-kit/block-elements#static_select](https://api.slack.com/reference/block-kit/block-elements#static_select)
@@ -988,33 +1037,33 @@ pub struct RadioButtons {
 /// assert_json_eq!(expected, output);
 /// ```
 #[impl_for(SectionElements, ActionElements, InputElements)]
-#[derive(TypedBuilder, Serialize)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
 pub struct StaticSelect {
     #[serde(rename = "type")]
     #[builder(setter(skip), default = String::from("static_select"))]
     block_type: String,
 
     #[builder(setter(into))]
-    placeholder: composition::PlainText,
+    pub placeholder: composition::PlainText,
 
     #[builder(setter(into))]
-    action_id: String,
+    pub action_id: String,
 
     // Technically, one of the following two is required, but I can't model that
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    options: Option<Vec<composition::PlainTextOptionItem>>,
+    pub options: Option<Vec<composition::PlainTextOptionItem>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    option_groups: Option<Vec<composition::OptionGroup>>,
+    pub option_groups: Option<Vec<composition::OptionGroup>>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    initial_option: Option<composition::PlainTextOptionItem>,
+    pub initial_option: Option<composition::PlainTextOptionItem>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    confirm: Option<composition::Confirmation>,
+    pub confirm: Option<composition::Confirmation>,
 }
 
 /// [https://api.slack.com/reference/block-kit/block-elements#external_select](https://api.slack.com/reference/block-kit/block-elements#external_select)
@@ -1045,29 +1094,29 @@ pub struct StaticSelect {
 /// assert_json_eq!(expected, output);
 /// ```
 #[impl_for(SectionElements, ActionElements, InputElements)]
-#[derive(TypedBuilder, Serialize)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
 pub struct ExternalSelect {
     #[serde(rename = "type")]
     #[builder(setter(skip), default = String::from("external_select"))]
     block_type: String,
 
     #[builder(setter(into))]
-    placeholder: composition::PlainText,
+    pub placeholder: composition::PlainText,
 
     #[builder(setter(into))]
-    action_id: String,
+    pub action_id: String,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    min_query_length: Option<i32>,
+    pub min_query_length: Option<i32>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    initial_option: Option<composition::PlainTextOptionItem>,
+    pub initial_option: Option<composition::PlainTextOptionItem>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    confirm: Option<composition::Confirmation>,
+    pub confirm: Option<composition::Confirmation>,
 }
 
 /// [https://api.slack.com/reference/block-kit/block-elements#users_select](https://api.slack.com/reference/block-kit/block-elements#users_select)
@@ -1096,25 +1145,25 @@ pub struct ExternalSelect {
 /// assert_json_eq!(expected, output);
 /// ```
 #[impl_for(SectionElements, ActionElements, InputElements)]
-#[derive(TypedBuilder, Serialize)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
 pub struct UsersSelect {
     #[serde(rename = "type")]
     #[builder(setter(skip), default = String::from("users_select"))]
     block_type: String,
 
     #[builder(setter(into))]
-    placeholder: composition::PlainText,
+    pub placeholder: composition::PlainText,
 
     #[builder(setter(into))]
-    action_id: String,
+    pub action_id: String,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    initial_user: Option<String>,
+    pub initial_user: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    confirm: Option<composition::Confirmation>,
+    pub confirm: Option<composition::Confirmation>,
 }
 
 /// [https://api.slack.com/reference/block-kit/block-elements#conversation_select](https://api.slack.com/reference/block-kit/block-elements#conversation_select)
@@ -1143,34 +1192,39 @@ pub struct UsersSelect {
 /// assert_json_eq!(expected, output);
 /// ```
 #[impl_for(SectionElements, ActionElements, InputElements)]
-#[derive(TypedBuilder, Serialize)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
 pub struct ConversationsSelect {
     #[serde(rename = "type")]
     #[builder(setter(skip), default = String::from("conversations_select"))]
     block_type: String,
 
     #[builder(setter(into))]
-    placeholder: composition::PlainText,
+    pub placeholder: composition::PlainText,
 
     #[builder(setter(into))]
-    action_id: String,
+    pub action_id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    pub initial_conversation: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    initial_conversation: Option<String>,
+    pub default_to_current_conversation: Option<bool>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    default_to_current_conversation: Option<bool>,
+    pub confirm: Option<composition::Confirmation>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    confirm: Option<composition::Confirmation>,
+    pub filter: Option<composition::Filter>,
 
+    /// Only for modals, in an input block. Submitting the modal then comes
+    /// with a `response_url` for the chosen conversation.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    filter: Option<composition::Filter>,
-    // TODO: response_url_enabled
+    pub response_url_enabled: Option<bool>,
 }
 
 /// [https://api.slack.com/reference/block-kit/block-elements#channel_select](https://api.slack.com/reference/block-kit/block-elements#channel_select)
@@ -1199,24 +1253,29 @@ pub struct ConversationsSelect {
 /// assert_json_eq!(expected, output);
 /// ```
 #[impl_for(SectionElements, ActionElements, InputElements)]
-#[derive(TypedBuilder, Serialize)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
 pub struct ChannelsSelect {
     #[serde(rename = "type")]
     #[builder(setter(skip), default = String::from("channels_select"))]
     block_type: String,
 
     #[builder(setter(into))]
-    placeholder: composition::PlainText,
+    pub placeholder: composition::PlainText,
 
     #[builder(setter(into))]
-    action_id: String,
+    pub action_id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    pub initial_channel: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    initial_channel: Option<String>,
+    pub confirm: Option<composition::Confirmation>,
 
+    /// Only works in a modal's input block. The `view_submission` then
+    /// carries a `response_url` for posting to the picked channel.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
-    confirm: Option<composition::Confirmation>,
-    // TODO: response_url_enabled
+    pub response_url_enabled: Option<bool>,
 }
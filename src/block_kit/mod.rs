@@ -1,3 +1,36 @@
+/// Implements `Deserialize` for one of the untagged enums, whose variants
+/// each carry their own `type` field. Deriving it with `#[serde(untagged)]`
+/// would take the first variant whose fields happen to line up (a
+/// `UsersSelect` and a `ChannelsSelect` look identical without their `type`),
+/// so instead we look at `type` and deserialize the variant it names.
+macro_rules! deserialize_by_type {
+    ($name:ident { $($($kind:literal)|+ => $variant:ident),+ $(,)? }) => {
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                use serde::de::Error;
+
+                let value = serde_json::Value::deserialize(deserializer)?;
+                let kind = match value.get("type").and_then(serde_json::Value::as_str) {
+                    Some(kind) => kind.to_owned(),
+                    None => return Err(D::Error::missing_field("type")),
+                };
+
+                match kind.as_str() {
+                    $($($kind)|+ => serde_json::from_value(value)
+                        .map($name::$variant)
+                        .map_err(D::Error::custom),)+
+                    other => Err(D::Error::unknown_variant(other, &[$($($kind),+),+])),
+                }
+            }
+        }
+    };
+}
+
 pub mod blocks;
 pub mod composition;
 pub mod elements;
+pub mod render;
+pub mod views;
@@ -0,0 +1,455 @@
+//! Renders blocks as a standalone HTML page, approximating how Slack shows
+//! them, e.g. to review a message's layout without posting it
+//!
+//! The page has its styles inline and no scripts, so it can be saved and
+//! opened anywhere. Only images are loaded from their URLs. Interactive
+//! elements are drawn but do nothing, and only `http`, `https` and `mailto`
+//! links are kept; any other link shows as its label.
+//!
+//! ```
+//! # use slack_lib::block_kit::{blocks::*, composition::*, elements::*};
+//! # use slack_lib::block_kit::render::html;
+//! let blocks: Vec<MessageBlock> = vec![
+//!     Section::builder()
+//!         .text(Markdown::from("*Deploy* of `main` finished"))
+//!         .accessory(
+//!             Button::builder()
+//!                 .text("Roll back")
+//!                 .action_id("rollback")
+//!                 .style(ButtonStyle::Danger)
+//!                 .build(),
+//!         )
+//!         .build()
+//!         .into(),
+//! ];
+//!
+//! let page = html::render(&blocks);
+//! assert!(page.starts_with("<!DOCTYPE html>"));
+//! assert!(page.contains(
+//!     "<div class=\"text\"><b>Deploy</b> of <code>main</code> finished</div>"
+//! ));
+//! assert!(page.contains("<span class=\"button danger\">Roll back</span>"));
+//! ```
+
+use super::{rich_text_plain, AnyBlock, Block, Label, Widget};
+use crate::block_kit::elements::{ButtonStyle, ContextElements};
+use crate::block_kit::views::Modal;
+use crate::mrkdwn::parser::{self, Broadcast, Node};
+
+/// A complete page showing blocks from a message, modal or home tab
+pub fn render<B: Block>(blocks: &[B]) -> String {
+    page(&fragment(blocks))
+}
+
+/// A complete page showing a modal, with its title and buttons
+///
+/// ```
+/// # use slack_lib::block_kit::{blocks::*, composition::*, elements::*, views::*};
+/// # use slack_lib::block_kit::render::html;
+/// let modal = Modal::builder()
+///     .title("New ticket")
+///     .submit("Create")
+///     .blocks(vec![Input::builder()
+///         .label("Summary")
+///         .element(PlainTextInput::builder().action_id("summary").build())
+///         .build()
+///         .into()])
+///     .build();
+///
+/// let page = html::modal(&modal);
+/// assert!(page.contains("<div class=\"modal-title\">New ticket</div>"));
+/// assert!(page.contains("<span class=\"button primary\">Create</span>"));
+/// ```
+pub fn modal(modal: &Modal) -> String {
+    let close = modal.close.as_ref().map_or("Cancel", |close| &close.text);
+    let submit = match &modal.submit {
+        Some(submit) => format!(
+            "<span class=\"button primary\">{}</span>",
+            escape(&submit.text)
+        ),
+        None => String::new(),
+    };
+
+    page(&format!(
+        "<div class=\"modal\">\
+         <div class=\"modal-title\">{}</div>\
+         {}\
+         <div class=\"modal-buttons\"><span class=\"button\">{}</span>{}</div>\
+         </div>",
+        escape(&modal.title.text),
+        fragment(&modal.blocks),
+        escape(close),
+        submit
+    ))
+}
+
+/// Just the blocks, as a `<div>`, to embed in another page. It needs the
+/// styles from [`render`] to look right.
+pub fn fragment<B: Block>(blocks: &[B]) -> String {
+    let mut html = String::from("<div class=\"blocks\">");
+    for block in blocks {
+        html.push_str(&render_block(block.as_any()));
+    }
+    html.push_str("</div>");
+    html
+}
+
+/// Convert mrkdwn to HTML. Mentions show their label, or the ID if there
+/// isn't one.
+///
+/// ```
+/// # use slack_lib::block_kit::render::html::from_mrkdwn;
+/// assert_eq!(
+///     from_mrkdwn("_Hi_ <@U024BE7LH|alice>, see <https://example.com?a=1&amp;b=2|this>"),
+///     "<i>Hi</i> <span class=\"mention\">@alice</span>, \
+///      see <a href=\"https://example.com?a=1&amp;b=2\">this</a>"
+/// );
+/// ```
+pub fn from_mrkdwn(mrkdwn: &str) -> String {
+    let mut html = String::new();
+    push_nodes(&parser::parse(mrkdwn), &mut html);
+    html
+}
+
+const STYLE: &str = "\
+body { margin: 0; padding: 24px; background: #f8f8f8; color: #1d1c1d; \
+font: 15px/1.47 -apple-system, BlinkMacSystemFont, 'Segoe UI', Lato, Helvetica, Arial, sans-serif; }
+a { color: #1264a3; text-decoration: none; }
+.blocks { max-width: 600px; padding: 8px 20px; background: #fff; border: 1px solid #ddd; border-radius: 8px; }
+.block { margin: 8px 0; }
+.header { font-size: 18px; font-weight: 900; }
+.divider { border: 0; border-top: 1px solid #ddd; margin: 16px 0; }
+.section { display: flex; gap: 12px; align-items: flex-start; }
+.section-body { flex: 1; min-width: 0; }
+.text { white-space: pre-wrap; word-wrap: break-word; }
+.fields { display: grid; grid-template-columns: 1fr 1fr; gap: 8px 16px; margin-top: 8px; }
+.accessory-image { width: 88px; height: 88px; object-fit: cover; border-radius: 6px; }
+.image img { max-width: 100%; border-radius: 6px; }
+.image figcaption { color: #616061; font-size: 13px; }
+.image { margin-left: 0; margin-right: 0; }
+.context { display: flex; flex-wrap: wrap; align-items: center; gap: 6px; color: #616061; font-size: 12px; }
+.context img { width: 20px; height: 20px; border-radius: 4px; }
+.actions { display: flex; flex-wrap: wrap; gap: 8px; }
+.button, .select, .datepicker, .overflow { display: inline-block; box-sizing: border-box; height: 32px; \
+line-height: 30px; padding: 0 12px; border: 1px solid #bbb; border-radius: 4px; background: #fff; color: #1d1c1d; \
+font-size: 14px; font-weight: 700; white-space: nowrap; }
+.button.primary { background: #007a5a; border-color: #007a5a; color: #fff; }
+.button.danger { background: #e01e5a; border-color: #e01e5a; color: #fff; }
+.select, .datepicker { font-weight: 400; min-width: 140px; }
+.select::after { content: ' \\25be'; float: right; margin-left: 8px; }
+.datepicker::before { content: '\\1f4c5  '; }
+.placeholder { color: #868686; }
+.overflow { padding: 0 8px; }
+.choices label { display: block; }
+.input label.label { display: block; font-weight: 700; margin-bottom: 4px; }
+.optional { color: #616061; font-weight: 400; }
+.input input[type=text], .input textarea, .section input[type=text], .section textarea, .actions input[type=text] { \
+box-sizing: border-box; width: 100%; padding: 8px; border: 1px solid #bbb; border-radius: 4px; font: inherit; }
+.hint { color: #616061; font-size: 13px; margin-top: 4px; }
+.mention { background: #e8f5fa; color: #1264a3; border-radius: 3px; padding: 0 2px; }
+code { background: #f6f6f6; border: 1px solid #ddd; border-radius: 3px; padding: 0 3px; color: #e01e5a; font-size: 12px; }
+pre { background: #f6f6f6; border: 1px solid #ddd; border-radius: 4px; padding: 8px; margin: 4px 0; \
+white-space: pre-wrap; font-size: 12px; }
+blockquote { margin: 4px 0; padding-left: 12px; border-left: 4px solid #ddd; }
+.modal { max-width: 520px; background: #fff; border-radius: 8px; box-shadow: 0 4px 12px rgba(0, 0, 0, 0.2); }
+.modal .blocks { border: 0; }
+.modal-title { padding: 16px 20px; font-size: 22px; font-weight: 900; border-bottom: 1px solid #ddd; }
+.modal-buttons { display: flex; justify-content: flex-end; gap: 8px; padding: 16px 20px; border-top: 1px solid #ddd; }
+";
+
+fn page(body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n\
+         <html>\n\
+         <head>\n\
+         <meta charset=\"utf-8\">\n\
+         <title>Block Kit preview</title>\n\
+         <style>\n{}</style>\n\
+         </head>\n\
+         <body>\n{}\n</body>\n\
+         </html>\n",
+        STYLE, body
+    )
+}
+
+fn render_block(block: AnyBlock<'_>) -> String {
+    match block {
+        AnyBlock::Actions(block) => {
+            let widgets: String = block
+                .elements
+                .iter()
+                .map(|element| render_widget(&element.into()))
+                .collect();
+            format!("<div class=\"block actions\">{}</div>", widgets)
+        }
+        AnyBlock::Context(block) => {
+            let elements: String = block
+                .elements
+                .iter()
+                .map(|element| match element {
+                    ContextElements::Image(image) => format!(
+                        "<img src=\"{}\" alt=\"{}\">",
+                        escape(&image.image_url),
+                        escape(&image.alt_text)
+                    ),
+                    ContextElements::Text(text) => {
+                        format!("<span>{}</span>", render_label(text.into()))
+                    }
+                })
+                .collect();
+            format!("<div class=\"block context\">{}</div>", elements)
+        }
+        AnyBlock::Divider(_) => String::from("<hr class=\"block divider\">"),
+        AnyBlock::Header(block) => format!(
+            "<div class=\"block header\">{}</div>",
+            escape(&block.text.text)
+        ),
+        AnyBlock::Image(block) => {
+            let title = match &block.title {
+                Some(title) => format!("<figcaption>{}</figcaption>", escape(&title.text)),
+                None => String::new(),
+            };
+            format!(
+                "<figure class=\"block image\">{}<img src=\"{}\" alt=\"{}\"></figure>",
+                title,
+                escape(&block.image_url),
+                escape(&block.alt_text)
+            )
+        }
+        AnyBlock::Input(block) => {
+            let optional = if block.optional.unwrap_or(false) {
+                " <span class=\"optional\">(optional)</span>"
+            } else {
+                ""
+            };
+            let hint = match &block.hint {
+                Some(hint) => format!("<div class=\"hint\">{}</div>", escape(&hint.text)),
+                None => String::new(),
+            };
+            format!(
+                "<div class=\"block input\"><label class=\"label\">{}{}</label>{}{}</div>",
+                escape(&block.label.text),
+                optional,
+                render_widget(&(&block.element).into()),
+                hint
+            )
+        }
+        AnyBlock::RichText(block) => format!(
+            "<div class=\"block rich-text text\">{}</div>",
+            escape(&rich_text_plain(&block.elements))
+        ),
+        AnyBlock::Section(block) => {
+            let mut html =
+                String::from("<div class=\"block section\"><div class=\"section-body\">");
+            if let Some(text) = &block.text {
+                html.push_str("<div class=\"text\">");
+                html.push_str(&render_label(text.into()));
+                html.push_str("</div>");
+            }
+            if let Some(fields) = &block.fields {
+                html.push_str("<div class=\"fields\">");
+                for field in fields {
+                    html.push_str("<div class=\"text\">");
+                    html.push_str(&render_label(field.into()));
+                    html.push_str("</div>");
+                }
+                html.push_str("</div>");
+            }
+            html.push_str("</div>");
+            if let Some(accessory) = &block.accessory {
+                html.push_str(&render_widget(&accessory.into()));
+            }
+            html.push_str("</div>");
+            html
+        }
+    }
+}
+
+fn render_label(label: Label<'_>) -> String {
+    match label {
+        Label::Plain(text) => escape(text),
+        Label::Mrkdwn(text) => from_mrkdwn(text),
+    }
+}
+
+fn render_widget(widget: &Widget<'_>) -> String {
+    match widget {
+        Widget::Button(button) => {
+            let class = match button.style.unwrap_or_default() {
+                ButtonStyle::Primary => "button primary",
+                ButtonStyle::Danger => "button danger",
+                ButtonStyle::Default => "button",
+            };
+            match button.url.as_deref().filter(|url| is_safe_url(url)) {
+                Some(url) => format!(
+                    "<a class=\"{}\" href=\"{}\">{}</a>",
+                    class,
+                    escape(url),
+                    escape(&button.text.text)
+                ),
+                None => format!(
+                    "<span class=\"{}\">{}</span>",
+                    class,
+                    escape(&button.text.text)
+                ),
+            }
+        }
+        Widget::Image(image) => format!(
+            "<img class=\"accessory-image\" src=\"{}\" alt=\"{}\">",
+            escape(&image.image_url),
+            escape(&image.alt_text)
+        ),
+        Widget::Choices { options, multiple } => {
+            let kind = if *multiple { "checkbox" } else { "radio" };
+            let options: String = options
+                .iter()
+                .map(|(label, selected)| {
+                    format!(
+                        "<label><input type=\"{}\" disabled{}> {}</label>",
+                        kind,
+                        if *selected { " checked" } else { "" },
+                        render_label(*label)
+                    )
+                })
+                .collect();
+            format!("<div class=\"choices\">{}</div>", options)
+        }
+        Widget::Menu {
+            label,
+            is_placeholder,
+        } => format!(
+            "<span class=\"select{}\">{}</span>",
+            if *is_placeholder { " placeholder" } else { "" },
+            escape(label)
+        ),
+        Widget::Datepicker { date, placeholder } => match date {
+            Some(date) => format!("<span class=\"datepicker\">{}</span>", escape(date)),
+            None => format!(
+                "<span class=\"datepicker placeholder\">{}</span>",
+                escape(placeholder)
+            ),
+        },
+        Widget::Overflow => String::from("<span class=\"overflow\">\u{22ef}</span>"),
+        Widget::TextInput {
+            value,
+            placeholder,
+            multiline,
+        } => {
+            let placeholder = match placeholder {
+                Some(placeholder) => format!(" placeholder=\"{}\"", escape(placeholder)),
+                None => String::new(),
+            };
+            let value = value.unwrap_or_default();
+            if *multiline {
+                format!(
+                    "<textarea rows=\"3\" disabled{}>{}</textarea>",
+                    placeholder,
+                    escape(value)
+                )
+            } else {
+                format!(
+                    "<input type=\"text\" disabled{} value=\"{}\">",
+                    placeholder,
+                    escape(value)
+                )
+            }
+        }
+    }
+}
+
+fn push_nodes(nodes: &[Node], html: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => html.push_str(&escape(text)),
+            Node::LineBreak => html.push('\n'),
+            Node::Bold(children) => push_wrapped("b", children, html),
+            Node::Italic(children) => push_wrapped("i", children, html),
+            Node::Strike(children) => push_wrapped("s", children, html),
+            Node::Quote(children) => push_wrapped("blockquote", children, html),
+            Node::Code(code) => {
+                html.push_str("<code>");
+                html.push_str(&escape(code));
+                html.push_str("</code>");
+            }
+            Node::CodeBlock(code) => {
+                html.push_str("<pre>");
+                html.push_str(&escape(code));
+                html.push_str("</pre>");
+            }
+            Node::Link { url, label } => {
+                let label = escape(label.as_deref().unwrap_or(url));
+                if is_safe_url(url) {
+                    html.push_str(&format!("<a href=\"{}\">{}</a>", escape(url), label));
+                } else {
+                    html.push_str(&label);
+                }
+            }
+            Node::User { id, label } | Node::Usergroup { id, label } => {
+                push_mention('@', label.as_deref().unwrap_or(id), html)
+            }
+            Node::Channel { id, label } => push_mention('#', label.as_deref().unwrap_or(id), html),
+            Node::Broadcast(broadcast) => push_mention(
+                '@',
+                match broadcast {
+                    Broadcast::Here => "here",
+                    Broadcast::Channel => "channel",
+                    Broadcast::Everyone => "everyone",
+                },
+                html,
+            ),
+            Node::Date {
+                link: Some(link),
+                fallback,
+                ..
+            } if is_safe_url(link) => {
+                html.push_str(&format!(
+                    "<a href=\"{}\">{}</a>",
+                    escape(link),
+                    escape(fallback)
+                ));
+            }
+            Node::Date { fallback, .. } => html.push_str(&escape(fallback)),
+            Node::Emoji(name) => {
+                html.push_str(&format!("<span class=\"emoji\">:{}:</span>", escape(name)));
+            }
+        }
+    }
+}
+
+fn push_wrapped(tag: &str, children: &[Node], html: &mut String) {
+    html.push_str(&format!("<{}>", tag));
+    push_nodes(children, html);
+    html.push_str(&format!("</{}>", tag));
+}
+
+fn push_mention(sigil: char, name: &str, html: &mut String) {
+    html.push_str(&format!(
+        "<span class=\"mention\">{}{}</span>",
+        sigil,
+        escape(name)
+    ));
+}
+
+/// Whether a link can go in an `href`. Anything else, like `javascript:`,
+/// could run in the page.
+fn is_safe_url(url: &str) -> bool {
+    ["http://", "https://", "mailto:"].iter().any(|scheme| {
+        url.get(..scheme.len())
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case(scheme))
+    })
+}
+
+/// Escape text for HTML, including in attribute values
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,393 @@
+//! Renders blocks outside of Slack
+//!
+//! Each renderer takes a slice of any of the three block types, through the
+//! [`Block`] trait:
+//!
+//! - [`text`], plain text for snapshots, and escaped for notification fallbacks
+//! - [`html`], a standalone page approximating how Slack shows them, for
+//!   reviewing layouts
+//! - [`terminal`], styled with ANSI escapes, for printing from CLIs
+
+use super::blocks::{
+    Actions, Context, Divider, Header, HomeTabBlock, Image, Input, MessageBlock, ModalBlock,
+    RichText, Section,
+};
+use super::composition::{OptionItem, PlainTextOptionItem, Text};
+use super::elements::{self, ActionElements, Button, InputElements, SectionElements};
+use serde_json::Value;
+
+pub mod html;
+pub mod terminal;
+pub mod text;
+
+/// A block from any surface
+#[derive(Debug, Clone, Copy)]
+pub enum AnyBlock<'a> {
+    Actions(&'a Actions),
+    Context(&'a Context),
+    Divider(&'a Divider),
+    Header(&'a Header),
+    Image(&'a Image),
+    Input(&'a Input),
+    RichText(&'a RichText),
+    Section(&'a Section),
+}
+
+/// Implemented by [`MessageBlock`], [`ModalBlock`] and [`HomeTabBlock`], so
+/// they can all be rendered the same way
+pub trait Block {
+    fn as_any(&self) -> AnyBlock<'_>;
+}
+
+impl Block for MessageBlock {
+    fn as_any(&self) -> AnyBlock<'_> {
+        match self {
+            MessageBlock::Actions(block) => AnyBlock::Actions(block),
+            MessageBlock::Context(block) => AnyBlock::Context(block),
+            MessageBlock::Divider(block) => AnyBlock::Divider(block),
+            MessageBlock::Header(block) => AnyBlock::Header(block),
+            MessageBlock::Image(block) => AnyBlock::Image(block),
+            MessageBlock::RichText(block) => AnyBlock::RichText(block),
+            MessageBlock::Section(block) => AnyBlock::Section(block),
+        }
+    }
+}
+
+impl Block for ModalBlock {
+    fn as_any(&self) -> AnyBlock<'_> {
+        match self {
+            ModalBlock::Actions(block) => AnyBlock::Actions(block),
+            ModalBlock::Context(block) => AnyBlock::Context(block),
+            ModalBlock::Divider(block) => AnyBlock::Divider(block),
+            ModalBlock::Header(block) => AnyBlock::Header(block),
+            ModalBlock::Image(block) => AnyBlock::Image(block),
+            ModalBlock::Input(block) => AnyBlock::Input(block),
+            ModalBlock::RichText(block) => AnyBlock::RichText(block),
+            ModalBlock::Section(block) => AnyBlock::Section(block),
+        }
+    }
+}
+
+impl Block for HomeTabBlock {
+    fn as_any(&self) -> AnyBlock<'_> {
+        match self {
+            HomeTabBlock::Actions(block) => AnyBlock::Actions(block),
+            HomeTabBlock::Context(block) => AnyBlock::Context(block),
+            HomeTabBlock::Divider(block) => AnyBlock::Divider(block),
+            HomeTabBlock::Header(block) => AnyBlock::Header(block),
+            HomeTabBlock::Image(block) => AnyBlock::Image(block),
+            HomeTabBlock::RichText(block) => AnyBlock::RichText(block),
+            HomeTabBlock::Section(block) => AnyBlock::Section(block),
+        }
+    }
+}
+
+/// Some text, and whether it's mrkdwn
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Label<'a> {
+    Plain(&'a str),
+    Mrkdwn(&'a str),
+}
+
+impl<'a> From<&'a Text> for Label<'a> {
+    fn from(text: &'a Text) -> Self {
+        match text {
+            Text::PlainText(text) => Label::Plain(&text.text),
+            Text::Markdown(text) => Label::Mrkdwn(&text.text),
+        }
+    }
+}
+
+/// The interactive elements, reduced to what they look like
+#[derive(Debug, Clone)]
+pub(crate) enum Widget<'a> {
+    Button(&'a Button),
+    Image(&'a elements::Image),
+    /// Checkboxes (`multiple`) or radio buttons, and whether each is selected
+    Choices {
+        options: Vec<(Label<'a>, bool)>,
+        multiple: bool,
+    },
+    /// A select menu, showing what's selected or its placeholder
+    Menu {
+        label: String,
+        is_placeholder: bool,
+    },
+    Datepicker {
+        date: Option<&'a str>,
+        placeholder: &'a str,
+    },
+    Overflow,
+    TextInput {
+        value: Option<&'a str>,
+        placeholder: Option<&'a str>,
+        multiline: bool,
+    },
+}
+
+impl<'a> Widget<'a> {
+    fn menu(selected: Vec<String>, placeholder: &str) -> Self {
+        let is_placeholder = selected.is_empty();
+        Widget::Menu {
+            label: if is_placeholder {
+                placeholder.to_owned()
+            } else {
+                selected.join(", ")
+            },
+            is_placeholder,
+        }
+    }
+
+    fn choices(options: &'a [OptionItem], selected: &[&OptionItem], multiple: bool) -> Self {
+        let value = |option: &OptionItem| match option {
+            OptionItem::PlainTextOptionItem(option) => option.value.clone(),
+            OptionItem::MarkdownOptionItem(option) => option.value.clone(),
+        };
+        let selected: Vec<String> = selected.iter().map(|option| value(option)).collect();
+
+        Widget::Choices {
+            options: options
+                .iter()
+                .map(|option| {
+                    let label = match option {
+                        OptionItem::PlainTextOptionItem(option) => Label::Plain(&option.text.text),
+                        OptionItem::MarkdownOptionItem(option) => Label::Mrkdwn(&option.text.text),
+                    };
+                    (label, selected.contains(&value(option)))
+                })
+                .collect(),
+            multiple,
+        }
+    }
+}
+
+fn option_texts<'a>(options: impl IntoIterator<Item = &'a PlainTextOptionItem>) -> Vec<String> {
+    options
+        .into_iter()
+        .map(|option| option.text.text.clone())
+        .collect()
+}
+
+fn prefixed<'a>(prefix: &str, ids: impl IntoIterator<Item = &'a String>) -> Vec<String> {
+    ids.into_iter()
+        .map(|id| format!("{}{}", prefix, id))
+        .collect()
+}
+
+/// Generates `From<&...Elements> for Widget`, since the three element enums
+/// share most of their variants
+macro_rules! widget_from {
+    ($name:ident { $($variant:ident),* $(,)? }) => {
+        impl<'a> From<&'a $name> for Widget<'a> {
+            // Not every arm uses the element
+            #[allow(unused_variables)]
+            fn from(element: &'a $name) -> Self {
+                match element {
+                    $($name::$variant(element) => widget_from!(@ $variant element),)*
+                }
+            }
+        }
+    };
+    (@ Button $element:ident) => { Widget::Button($element) };
+    (@ Image $element:ident) => { Widget::Image($element) };
+    (@ Checkboxes $element:ident) => {
+        Widget::choices(
+            &$element.options,
+            &$element.initial_options.iter().flatten().collect::<Vec<_>>(),
+            true,
+        )
+    };
+    (@ RadioButtons $element:ident) => {
+        Widget::choices(&$element.options, &$element.initial_option.iter().collect::<Vec<_>>(), false)
+    };
+    (@ Datepicker $element:ident) => {
+        Widget::Datepicker {
+            date: $element.initial_date.as_deref(),
+            placeholder: $element
+                .placeholder
+                .as_ref()
+                .map(|placeholder| placeholder.text.as_str())
+                .unwrap_or("Select a date"),
+        }
+    };
+    (@ Overflow $element:ident) => {
+        Widget::Overflow
+    };
+    (@ PlainTextInput $element:ident) => {
+        Widget::TextInput {
+            value: $element.initial_value.as_deref(),
+            placeholder: $element
+                .placeholder
+                .as_ref()
+                .map(|placeholder| placeholder.text.as_str()),
+            multiline: $element.multiline.unwrap_or(false),
+        }
+    };
+    (@ StaticSelect $element:ident) => {
+        Widget::menu(option_texts(&$element.initial_option), &$element.placeholder.text)
+    };
+    (@ ExternalSelect $element:ident) => {
+        Widget::menu(option_texts(&$element.initial_option), &$element.placeholder.text)
+    };
+    (@ MultiStaticSelect $element:ident) => {
+        Widget::menu(option_texts($element.initial_options.iter().flatten()),
+            &$element.placeholder.text,
+        )
+    };
+    (@ MultiExternalSelect $element:ident) => {
+        Widget::menu(option_texts($element.initial_options.iter().flatten()),
+            &$element.placeholder.text,
+        )
+    };
+    (@ UsersSelect $element:ident) => {
+        Widget::menu(prefixed("@", &$element.initial_user), &$element.placeholder.text)
+    };
+    (@ MultiUsersSelect $element:ident) => {
+        Widget::menu(prefixed("@", $element.initial_users.iter().flatten()),
+            &$element.placeholder.text,
+        )
+    };
+    (@ ConversationsSelect $element:ident) => {
+        Widget::menu(prefixed("#", &$element.initial_conversation),
+            &$element.placeholder.text,
+        )
+    };
+    (@ MultiConversationsSelect $element:ident) => {
+        Widget::menu(prefixed("#", $element.initial_conversations.iter().flatten()),
+            &$element.placeholder.text,
+        )
+    };
+    (@ ChannelsSelect $element:ident) => {
+        Widget::menu(prefixed("#", &$element.initial_channel), &$element.placeholder.text)
+    };
+    (@ MultiChannelsSelect $element:ident) => {
+        Widget::menu(prefixed("#", $element.initial_channels.iter().flatten()),
+            &$element.placeholder.text,
+        )
+    };
+}
+
+widget_from!(SectionElements {
+    Button,
+    Checkboxes,
+    Datepicker,
+    Image,
+    MultiStaticSelect,
+    MultiExternalSelect,
+    MultiUsersSelect,
+    MultiConversationsSelect,
+    MultiChannelsSelect,
+    Overflow,
+    PlainTextInput,
+    RadioButtons,
+    StaticSelect,
+    ExternalSelect,
+    UsersSelect,
+    ConversationsSelect,
+    ChannelsSelect,
+});
+
+widget_from!(ActionElements {
+    Button,
+    Checkboxes,
+    Datepicker,
+    Overflow,
+    PlainTextInput,
+    RadioButtons,
+    StaticSelect,
+    ExternalSelect,
+    UsersSelect,
+    ConversationsSelect,
+    ChannelsSelect,
+});
+
+widget_from!(InputElements {
+    Button,
+    Checkboxes,
+    Datepicker,
+    MultiStaticSelect,
+    MultiExternalSelect,
+    MultiUsersSelect,
+    MultiConversationsSelect,
+    MultiChannelsSelect,
+    PlainTextInput,
+    RadioButtons,
+    StaticSelect,
+    ExternalSelect,
+    UsersSelect,
+    ConversationsSelect,
+    ChannelsSelect,
+});
+
+/// The text in a rich text block's elements, which are kept as JSON. Lists
+/// are bulleted and quotes prefixed with `> `.
+pub(crate) fn rich_text_plain(elements: &[Value]) -> String {
+    let mut text = String::new();
+    for element in elements {
+        rich_text_element(element, &mut text);
+    }
+    text.trim_end().to_owned()
+}
+
+fn rich_text_element(element: &Value, text: &mut String) {
+    let field = |name: &str| {
+        element
+            .get(name)
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+    };
+    let children = || {
+        element
+            .get("elements")
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    };
+
+    match field("type") {
+        "text" => text.push_str(field("text")),
+        "link" => text.push_str(match field("text") {
+            "" => field("url"),
+            label => label,
+        }),
+        "user" => text.push_str(&format!("@{}", field("user_id"))),
+        "usergroup" => text.push_str(&format!("@{}", field("usergroup_id"))),
+        "channel" => text.push_str(&format!("#{}", field("channel_id"))),
+        "broadcast" => text.push_str(&format!("@{}", field("range"))),
+        "emoji" => text.push_str(&format!(":{}:", field("name"))),
+        "date" => text.push_str(field("fallback")),
+        "rich_text_list" => {
+            for (index, item) in children().iter().enumerate() {
+                let marker = match field("style") {
+                    "ordered" => format!("{}. ", index + 1),
+                    _ => String::from("\u{2022} "),
+                };
+                text.push_str(&marker);
+                rich_text_element(item, text);
+                text.push('\n');
+            }
+        }
+        "rich_text_quote" => {
+            let mut quote = String::new();
+            for child in children() {
+                rich_text_element(child, &mut quote);
+            }
+            for line in quote.trim_end().lines() {
+                text.push_str("> ");
+                text.push_str(line);
+                text.push('\n');
+            }
+        }
+        "rich_text_preformatted" => {
+            for child in children() {
+                rich_text_element(child, text);
+            }
+            text.push('\n');
+        }
+        _ => {
+            for child in children() {
+                rich_text_element(child, text);
+            }
+        }
+    }
+}
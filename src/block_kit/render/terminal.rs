@@ -0,0 +1,430 @@
+//! Renders blocks for a terminal, styled with ANSI escapes, e.g. so a CLI can
+//! print the same notification it sends to Slack
+//!
+//! Headers are bold, dividers are drawn as a rule, section fields are laid
+//! out in two columns and context is dimmed. Buttons are shown as bracketed
+//! labels, green for [`ButtonStyle::Primary`] and red for
+//! [`ButtonStyle::Danger`]. mrkdwn is styled too: bold, italic, strikethrough,
+//! code in cyan and mentions in blue.
+//!
+//! ```
+//! # use slack_lib::block_kit::{blocks::*, composition::*, elements::*};
+//! # use slack_lib::block_kit::render::terminal::Terminal;
+//! let blocks: Vec<MessageBlock> = vec![
+//!     Header::builder().text("Deploy finished").build().into(),
+//!     Divider::builder().build().into(),
+//!     Section::builder()
+//!         .text(Markdown::from("*main* is live"))
+//!         .accessory(
+//!             Button::builder()
+//!                 .text("Roll back")
+//!                 .action_id("rollback")
+//!                 .style(ButtonStyle::Danger)
+//!                 .build(),
+//!         )
+//!         .build()
+//!         .into(),
+//! ];
+//!
+//! assert_eq!(
+//!     Terminal::new().width(20).render(&blocks),
+//!     "\x1b[1mDeploy finished\x1b[0m\n\
+//!      \u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\
+//!      \u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\n\
+//!      \x1b[1mmain\x1b[0m is live\n\
+//!      \x1b[31m[Roll back]\x1b[0m"
+//! );
+//!
+//! // Without colours, e.g. when not writing to a TTY
+//! assert_eq!(
+//!     Terminal::new().width(20).colors(false).render(&blocks[2..]),
+//!     "main is live\n[Roll back]"
+//! );
+//! ```
+//!
+//! [`ButtonStyle::Primary`]: crate::block_kit::elements::ButtonStyle::Primary
+//! [`ButtonStyle::Danger`]: crate::block_kit::elements::ButtonStyle::Danger
+
+use super::{rich_text_plain, AnyBlock, Block, Label, Widget};
+use crate::block_kit::elements::{ButtonStyle, ContextElements};
+use crate::mrkdwn::parser::{self, Broadcast, Node};
+
+const BOLD: &str = "1";
+const DIM: &str = "2";
+const ITALIC: &str = "3";
+const UNDERLINE: &str = "4";
+const STRIKE: &str = "9";
+const RED: &str = "31";
+const GREEN: &str = "32";
+const BLUE: &str = "34";
+const CYAN: &str = "36";
+const RESET: &str = "\x1b[0m";
+
+/// Space between the two columns of fields
+const GUTTER: usize = 2;
+
+/// Renders blocks for a terminal. Defaults to 80 columns, with colours.
+#[derive(Debug, Clone, Copy)]
+pub struct Terminal {
+    width: usize,
+    colors: bool,
+}
+
+impl Default for Terminal {
+    fn default() -> Self {
+        Self {
+            width: 80,
+            colors: true,
+        }
+    }
+}
+
+impl Terminal {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// How wide to draw dividers and section fields
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Whether to use ANSI escapes at all. Turn this off when the output
+    /// isn't a terminal.
+    pub fn colors(mut self, colors: bool) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    /// Render blocks from a message, modal or home tab, without a trailing
+    /// newline
+    pub fn render<B: Block>(&self, blocks: &[B]) -> String {
+        let lines: Vec<String> = blocks
+            .iter()
+            .map(|block| self.render_block(block.as_any()))
+            .filter(|text| !text.is_empty())
+            .collect();
+        lines.join("\n")
+    }
+
+    /// Style mrkdwn with ANSI escapes
+    ///
+    /// ```
+    /// # use slack_lib::block_kit::render::terminal::Terminal;
+    /// assert_eq!(
+    ///     Terminal::new().mrkdwn("_so_ *very `done`*"),
+    ///     "\x1b[3mso\x1b[0m \x1b[1mvery \x1b[36mdone\x1b[0m\x1b[1m\x1b[0m"
+    /// );
+    /// ```
+    pub fn mrkdwn(&self, mrkdwn: &str) -> String {
+        let mut styler = self.styler();
+        styler.nodes(&parser::parse(mrkdwn));
+        styler.out
+    }
+
+    fn styler(&self) -> Styler {
+        Styler {
+            colors: self.colors,
+            stack: Vec::new(),
+            out: String::new(),
+            quoted: false,
+        }
+    }
+
+    fn render_block(&self, block: AnyBlock<'_>) -> String {
+        let mut styler = self.styler();
+        match block {
+            AnyBlock::Actions(block) => {
+                for (index, element) in block.elements.iter().enumerate() {
+                    if index > 0 {
+                        styler.text(" ");
+                    }
+                    styler.widget(&element.into());
+                }
+            }
+            AnyBlock::Context(block) => styler.styled(DIM, |styler| {
+                for (index, element) in block.elements.iter().enumerate() {
+                    if index > 0 {
+                        styler.text(" ");
+                    }
+                    match element {
+                        ContextElements::Image(image) => styler.text(&image.alt_text),
+                        ContextElements::Text(text) => styler.label(text.into()),
+                    }
+                }
+            }),
+            AnyBlock::Divider(_) => styler.text(&"\u{2500}".repeat(self.width)),
+            AnyBlock::Header(block) => styler.styled(BOLD, |styler| styler.text(&block.text.text)),
+            AnyBlock::Image(block) => {
+                if let Some(title) = &block.title {
+                    styler.styled(BOLD, |styler| styler.text(&title.text));
+                    styler.text("\n");
+                }
+                styler.styled(DIM, |styler| {
+                    styler.text(&format!("[image: {}]", block.alt_text))
+                });
+            }
+            AnyBlock::Input(block) => {
+                styler.styled(BOLD, |styler| styler.text(&block.label.text));
+                if block.optional.unwrap_or(false) {
+                    styler.styled(DIM, |styler| styler.text(" (optional)"));
+                }
+                styler.text("\n");
+                styler.widget(&(&block.element).into());
+                if let Some(hint) = &block.hint {
+                    styler.text("\n");
+                    styler.styled(DIM, |styler| styler.text(&hint.text));
+                }
+            }
+            AnyBlock::RichText(block) => styler.text(&rich_text_plain(&block.elements)),
+            AnyBlock::Section(block) => {
+                if let Some(text) = &block.text {
+                    styler.label(text.into());
+                }
+                if let Some(fields) = &block.fields {
+                    let fields: Vec<String> = fields
+                        .iter()
+                        .map(|field| {
+                            let mut styler = self.styler();
+                            styler.label(field.into());
+                            styler.out
+                        })
+                        .collect();
+                    if !styler.out.is_empty() {
+                        styler.text("\n");
+                    }
+                    // Already filtered, and styled
+                    styler.out.push_str(&self.columns(&fields));
+                }
+                if let Some(accessory) = &block.accessory {
+                    if !styler.out.is_empty() {
+                        styler.text("\n");
+                    }
+                    styler.widget(&accessory.into());
+                }
+            }
+        }
+        styler.out
+    }
+
+    /// Lay out fields two to a row
+    fn columns(&self, fields: &[String]) -> String {
+        let column = self.width.saturating_sub(GUTTER) / 2;
+        let mut lines = Vec::new();
+
+        for row in fields.chunks(2) {
+            let left: Vec<&str> = row[0].split('\n').collect();
+            let right: Vec<&str> = match row.get(1) {
+                Some(field) => field.split('\n').collect(),
+                None => {
+                    lines.extend(left.iter().map(|line| line.to_string()));
+                    continue;
+                }
+            };
+
+            for index in 0..left.len().max(right.len()) {
+                let left = left.get(index).copied().unwrap_or_default();
+                let right = right.get(index).copied().unwrap_or_default();
+                let padding = column.saturating_sub(visible_width(left));
+                let mut line = left.to_owned();
+                if self.colors && left.contains('\x1b') && !left.ends_with(RESET) {
+                    // Don't let an unclosed style run into the next column
+                    line.push_str(RESET);
+                }
+                line.push_str(&" ".repeat(padding + GUTTER));
+                line.push_str(right);
+                lines.push(line.trim_end().to_owned());
+            }
+        }
+
+        lines.join("\n")
+    }
+}
+
+/// Writes text, keeping track of which styles are on, since turning one off
+/// means resetting them all
+struct Styler {
+    colors: bool,
+    stack: Vec<&'static str>,
+    out: String,
+    quoted: bool,
+}
+
+impl Styler {
+    /// Text is mostly from Slack, so control characters are dropped rather
+    /// than letting it move the cursor or sneak in its own escapes
+    fn text(&mut self, text: &str) {
+        self.out.extend(text.chars().filter_map(|c| match c {
+            '\n' => Some(c),
+            '\t' => Some(' '),
+            c if c.is_control() => None,
+            c => Some(c),
+        }));
+    }
+
+    fn styled(&mut self, code: &'static str, f: impl FnOnce(&mut Self)) {
+        self.stack.push(code);
+        self.escape(code);
+        f(self);
+        self.stack.pop();
+        if self.colors {
+            self.out.push_str(RESET);
+            for code in self.stack.clone() {
+                self.escape(code);
+            }
+        }
+    }
+
+    fn escape(&mut self, code: &str) {
+        if self.colors {
+            self.out.push_str("\x1b[");
+            self.out.push_str(code);
+            self.out.push('m');
+        }
+    }
+
+    fn label(&mut self, label: Label<'_>) {
+        match label {
+            Label::Plain(text) => self.text(text),
+            Label::Mrkdwn(text) => self.nodes(&parser::parse(text)),
+        }
+    }
+
+    fn widget(&mut self, widget: &Widget<'_>) {
+        match widget {
+            Widget::Button(button) => {
+                let label = format!("[{}]", button.text.text);
+                match button.style.unwrap_or_default() {
+                    ButtonStyle::Primary => self.styled(GREEN, |styler| styler.text(&label)),
+                    ButtonStyle::Danger => self.styled(RED, |styler| styler.text(&label)),
+                    ButtonStyle::Default => self.text(&label),
+                }
+            }
+            Widget::Image(image) => {
+                self.styled(DIM, |styler| {
+                    styler.text(&format!("[image: {}]", image.alt_text))
+                });
+            }
+            Widget::Choices { options, multiple } => {
+                for (index, (label, selected)) in options.iter().enumerate() {
+                    if index > 0 {
+                        self.text(" ");
+                    }
+                    self.text(match (multiple, selected) {
+                        (true, true) => "[x] ",
+                        (true, false) => "[ ] ",
+                        (false, true) => "(*) ",
+                        (false, false) => "( ) ",
+                    });
+                    self.label(*label);
+                }
+            }
+            Widget::Menu {
+                label,
+                is_placeholder,
+            } => self.field(label, *is_placeholder, " \u{25be}"),
+            Widget::Datepicker { date, placeholder } => match date {
+                Some(date) => self.field(date, false, " \u{25be}"),
+                None => self.field(placeholder, true, " \u{25be}"),
+            },
+            Widget::Overflow => self.text("[\u{2026}]"),
+            Widget::TextInput {
+                value, placeholder, ..
+            } => match (value, placeholder) {
+                (Some(value), _) => self.field(value, false, ""),
+                (None, placeholder) => self.field(placeholder.unwrap_or_default(), true, ""),
+            },
+        }
+    }
+
+    /// Something in brackets, dimmed if it's a placeholder
+    fn field(&mut self, text: &str, is_placeholder: bool, suffix: &str) {
+        self.text("[");
+        if is_placeholder {
+            self.styled(DIM, |styler| styler.text(text));
+        } else {
+            self.text(text);
+        }
+        self.text(suffix);
+        self.text("]");
+    }
+
+    fn nodes(&mut self, nodes: &[Node]) {
+        for node in nodes {
+            match node {
+                Node::Text(text) => self.text(text),
+                Node::LineBreak => {
+                    self.text("\n");
+                    if self.quoted {
+                        self.quote_bar();
+                    }
+                }
+                Node::Bold(children) => self.styled(BOLD, |styler| styler.nodes(children)),
+                Node::Italic(children) => self.styled(ITALIC, |styler| styler.nodes(children)),
+                Node::Strike(children) => self.styled(STRIKE, |styler| styler.nodes(children)),
+                Node::Code(code) => self.styled(CYAN, |styler| styler.text(code)),
+                Node::CodeBlock(code) => {
+                    if !self.out.is_empty() && !self.out.ends_with('\n') {
+                        self.text("\n");
+                    }
+                    self.styled(CYAN, |styler| styler.text(code));
+                }
+                Node::Quote(children) => {
+                    self.quote_bar();
+                    self.quoted = true;
+                    self.nodes(children);
+                    self.quoted = false;
+                }
+                Node::Link { url, label } => match label {
+                    Some(label) if label != url => {
+                        self.styled(UNDERLINE, |styler| styler.text(label));
+                        self.styled(DIM, |styler| styler.text(&format!(" ({})", url)));
+                    }
+                    _ => self.styled(UNDERLINE, |styler| styler.text(url)),
+                },
+                Node::User { id, label } | Node::Usergroup { id, label } => {
+                    self.mention('@', label.as_deref().unwrap_or(id))
+                }
+                Node::Channel { id, label } => self.mention('#', label.as_deref().unwrap_or(id)),
+                Node::Broadcast(broadcast) => self.mention(
+                    '@',
+                    match broadcast {
+                        Broadcast::Here => "here",
+                        Broadcast::Channel => "channel",
+                        Broadcast::Everyone => "everyone",
+                    },
+                ),
+                Node::Date { fallback, .. } => self.text(fallback),
+                Node::Emoji(name) => self.text(&format!(":{}:", name)),
+            }
+        }
+    }
+
+    fn mention(&mut self, sigil: char, name: &str) {
+        self.styled(BLUE, |styler| styler.text(&format!("{}{}", sigil, name)));
+    }
+
+    fn quote_bar(&mut self) {
+        self.styled(DIM, |styler| styler.text("\u{2502} "));
+    }
+}
+
+/// How many columns `text` takes up, ignoring escapes. Assumes every
+/// character is one column wide.
+fn visible_width(text: &str) -> usize {
+    let mut width = 0;
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            for c in chars.by_ref() {
+                if c == 'm' {
+                    break;
+                }
+            }
+        } else {
+            width += 1;
+        }
+    }
+    width
+}
@@ -0,0 +1,243 @@
+//! Renders blocks as plain text, for snapshot tests, or with [`fallback`] for
+//! the `text` of a message, which Slack shows in notifications
+//!
+//! Each block goes on its own line(s). mrkdwn is flattened to its text, so
+//! mentions show as `@name` (or `@U024BE7LH` without a label) and links as
+//! their label. Buttons and menus are shown in brackets.
+//!
+//! Entities are decoded, so [`render`]'s output isn't safe to send back to
+//! Slack: `&lt;!channel&gt;` would become a real `<!channel>`.
+//!
+//! ```
+//! # use slack_lib::block_kit::{blocks::*, composition::*, elements::*};
+//! # use slack_lib::block_kit::render::text;
+//! let blocks: Vec<MessageBlock> = vec![
+//!     Header::builder().text("Deploy finished").build().into(),
+//!     Section::builder()
+//!         .text(Markdown::from("*main* was deployed by <@U024BE7LH|alice>"))
+//!         .fields(vec![
+//!             Markdown::from("*Env*\nproduction").into(),
+//!             Markdown::from("*Took*\n4m 12s").into(),
+//!         ])
+//!         .build()
+//!         .into(),
+//!     Divider::builder().build().into(),
+//!     Actions::builder()
+//!         .elements(vec![
+//!             Button::builder().text("Roll back").action_id("rollback").build().into(),
+//!             Button::builder().text("View logs").action_id("logs").build().into(),
+//!         ])
+//!         .build()
+//!         .into(),
+//! ];
+//!
+//! assert_eq!(
+//!     text::render(&blocks),
+//!     "Deploy finished\n\
+//!      main was deployed by @alice\n\
+//!      Env\nproduction\n\
+//!      Took\n4m 12s\n\
+//!      ---\n\
+//!      [Roll back] [View logs]"
+//! );
+//! ```
+
+use super::{rich_text_plain, AnyBlock, Block, Label, Widget};
+use crate::block_kit::elements::ContextElements;
+use crate::mrkdwn::escape;
+use crate::mrkdwn::parser::{self, Broadcast, Node};
+
+/// Render blocks from a message, modal or home tab as plain text
+pub fn render<B: Block>(blocks: &[B]) -> String {
+    let lines: Vec<String> = blocks
+        .iter()
+        .map(|block| render_block(block.as_any()))
+        .filter(|text| !text.is_empty())
+        .collect();
+    lines.join("\n")
+}
+
+/// Render blocks as a message's `text`, the fallback Slack shows in
+/// notifications. This is [`render`]'s output with `&`, `<` and `>` escaped
+/// again, so nothing in it turns back into a mention or link.
+///
+/// ```
+/// # use slack_lib::block_kit::{blocks::*, composition::*};
+/// # use slack_lib::block_kit::render::text;
+/// let blocks: Vec<MessageBlock> = vec![Section::builder()
+///     .text(Markdown::from("*hi* &lt;!channel&gt; <!here>"))
+///     .build()
+///     .into()];
+///
+/// assert_eq!(text::render(&blocks), "hi <!channel> @here");
+/// assert_eq!(text::fallback(&blocks), "hi &lt;!channel&gt; @here");
+/// ```
+pub fn fallback<B: Block>(blocks: &[B]) -> String {
+    // Everything the renderer adds itself is free of these, apart from the
+    // `>` of quotes, which Slack reads the same way escaped
+    escape(&render(blocks))
+}
+
+/// Flatten mrkdwn to plain text, dropping the formatting
+///
+/// ```
+/// # use slack_lib::block_kit::render::text::from_mrkdwn;
+/// assert_eq!(
+///     from_mrkdwn("_Ping_ <!here>: <https://example.com|the docs> &amp; <#C024BE7LR>"),
+///     "Ping @here: the docs & #C024BE7LR"
+/// );
+/// ```
+pub fn from_mrkdwn(mrkdwn: &str) -> String {
+    let mut text = String::new();
+    push_nodes(&parser::parse(mrkdwn), &mut text);
+    text
+}
+
+fn render_block(block: AnyBlock<'_>) -> String {
+    match block {
+        AnyBlock::Actions(block) => {
+            let widgets: Vec<String> = block
+                .elements
+                .iter()
+                .map(|element| render_widget(&element.into()))
+                .collect();
+            widgets.join(" ")
+        }
+        AnyBlock::Context(block) => {
+            let elements: Vec<String> = block
+                .elements
+                .iter()
+                .map(|element| match element {
+                    ContextElements::Image(image) => image.alt_text.clone(),
+                    ContextElements::Text(text) => render_label(text.into()),
+                })
+                .collect();
+            elements.join(" ")
+        }
+        AnyBlock::Divider(_) => String::from("---"),
+        AnyBlock::Header(block) => block.text.text.clone(),
+        AnyBlock::Image(block) => match &block.title {
+            Some(title) => format!("{}\n{}", title.text, block.alt_text),
+            None => block.alt_text.clone(),
+        },
+        AnyBlock::Input(block) => {
+            let mut text = block.label.text.clone();
+            if block.optional.unwrap_or(false) {
+                text.push_str(" (optional)");
+            }
+            text.push_str(": ");
+            text.push_str(&render_widget(&(&block.element).into()));
+            if let Some(hint) = &block.hint {
+                text.push('\n');
+                text.push_str(&hint.text);
+            }
+            text
+        }
+        AnyBlock::RichText(block) => rich_text_plain(&block.elements),
+        AnyBlock::Section(block) => {
+            let mut lines: Vec<String> = block
+                .text
+                .iter()
+                .map(|text| render_label(text.into()))
+                .collect();
+            lines.extend(
+                block
+                    .fields
+                    .iter()
+                    .flatten()
+                    .map(|field| render_label(field.into())),
+            );
+            if let Some(accessory) = &block.accessory {
+                lines.push(render_widget(&accessory.into()));
+            }
+            lines.retain(|line| !line.is_empty());
+            lines.join("\n")
+        }
+    }
+}
+
+fn render_label(label: Label<'_>) -> String {
+    match label {
+        Label::Plain(text) => text.to_owned(),
+        Label::Mrkdwn(text) => from_mrkdwn(text),
+    }
+}
+
+fn render_widget(widget: &Widget<'_>) -> String {
+    match widget {
+        Widget::Button(button) => format!("[{}]", button.text.text),
+        Widget::Image(image) => image.alt_text.clone(),
+        Widget::Choices { options, multiple } => {
+            let options: Vec<String> = options
+                .iter()
+                .map(|(label, selected)| {
+                    let marker = match (multiple, selected) {
+                        (true, true) => "[x]",
+                        (true, false) => "[ ]",
+                        (false, true) => "(*)",
+                        (false, false) => "( )",
+                    };
+                    format!("{} {}", marker, render_label(*label))
+                })
+                .collect();
+            options.join(" ")
+        }
+        Widget::Menu { label, .. } => format!("[{} \u{25be}]", label),
+        Widget::Datepicker { date, placeholder } => {
+            format!("[{} \u{25be}]", date.unwrap_or(placeholder))
+        }
+        Widget::Overflow => String::from("[\u{2026}]"),
+        Widget::TextInput {
+            value, placeholder, ..
+        } => {
+            format!("[{}]", value.or(*placeholder).unwrap_or_default())
+        }
+    }
+}
+
+fn push_nodes(nodes: &[Node], text: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(value) | Node::Code(value) => text.push_str(value),
+            Node::LineBreak => text.push('\n'),
+            Node::Bold(children) | Node::Italic(children) | Node::Strike(children) => {
+                push_nodes(children, text)
+            }
+            Node::CodeBlock(code) => {
+                if !text.is_empty() && !text.ends_with('\n') {
+                    text.push('\n');
+                }
+                text.push_str(code);
+            }
+            Node::Quote(children) => {
+                let mut quote = String::new();
+                push_nodes(children, &mut quote);
+                let lines: Vec<String> = quote
+                    .split('\n')
+                    .map(|line| format!("> {}", line))
+                    .collect();
+                text.push_str(&lines.join("\n"));
+            }
+            Node::Link { url, label } => text.push_str(label.as_deref().unwrap_or(url)),
+            Node::User { id, label } | Node::Usergroup { id, label } => {
+                text.push('@');
+                text.push_str(label.as_deref().unwrap_or(id));
+            }
+            Node::Channel { id, label } => {
+                text.push('#');
+                text.push_str(label.as_deref().unwrap_or(id));
+            }
+            Node::Broadcast(broadcast) => text.push_str(match broadcast {
+                Broadcast::Here => "@here",
+                Broadcast::Channel => "@channel",
+                Broadcast::Everyone => "@everyone",
+            }),
+            Node::Date { fallback, .. } => text.push_str(fallback),
+            Node::Emoji(name) => {
+                text.push(':');
+                text.push_str(name);
+                text.push(':');
+            }
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use super::blocks;
+use super::composition;
+
+/// Either of the two view surfaces. This is what `views.update` takes, since
+/// it can update both.
+#[derive(Serialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum View {
+    Modal(Modal),
+    HomeTab(HomeTab),
+}
+
+deserialize_by_type!(View {
+    "modal" => Modal,
+    "home" => HomeTab,
+});
+
+/// [https://api.slack.com/reference/surfaces/views#modal](https://api.slack.com/reference/surfaces/views#modal)
+///
+/// Create a builder with [`Modal::builder()`]
+///
+/// Available fields:
+///
+/// - `.title(`[`Into`]`<`[`PlainText`](composition::PlainText)`>)` - required
+/// - `.blocks(`[`Into`]`<`[`Vec`]`<`[`ModalBlock`](blocks::ModalBlock)`>>)` -
+///   required
+/// - `.close(`[`Into`]`<`[`PlainText`](composition::PlainText)`>)` - optional
+/// - `.submit(`[`Into`]`<`[`PlainText`](composition::PlainText)`>)` - optional,
+///   but required if there are any `Input` blocks
+/// - `.private_metadata(`[`Into`]`<`[`String`]`>)` - optional
+/// - `.callback_id(`[`Into`]`<`[`String`]`>)` - optional
+/// - `.clear_on_close(`[`bool`]`)` - optional
+/// - `.notify_on_close(`[`bool`]`)` - optional
+/// - `.external_id(`[`Into`]`<`[`String`]`>)` - optional
+/// - `.submit_disabled(`[`bool`]`)` - optional
+///
+/// Finalise the builder with `.build()` to retrieve a [`Modal`]
+///
+/// ```
+/// # use assert_json_diff::assert_json_eq;
+/// # use serde_json::json;
+/// # use slack_lib::block_kit::blocks::*;
+/// # use slack_lib::block_kit::composition::*;
+/// # use slack_lib::block_kit::views::*;
+/// let expected = json!({
+///   "type": "modal",
+///   "title": {
+///     "type": "plain_text",
+///     "text": "My App"
+///   },
+///   "close": {
+///     "type": "plain_text",
+///     "text": "Cancel"
+///   },
+///   "blocks": [
+///     {
+///       "type": "section",
+///       "text": {
+///         "type": "mrkdwn",
+///         "text": "Hello from a modal"
+///       }
+///     }
+///   ],
+///   "callback_id": "greeting"
+/// });
+///
+/// let modal = Modal::builder()
+///     .title("My App")
+///     .close("Cancel")
+///     .blocks(vec![Section::builder()
+///         .text(Markdown::from("Hello from a modal"))
+///         .build()
+///         .into()])
+///     .callback_id("greeting")
+///     .build();
+///
+/// let output = serde_json::to_value(&modal).unwrap();
+/// assert_json_eq!(expected, output);
+/// ```
+#[impl_for(View)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
+pub struct Modal {
+    #[serde(rename = "type")]
+    #[builder(setter(skip), default = String::from("modal"))]
+    block_type: String,
+
+    #[builder(setter(into))]
+    pub title: composition::PlainText,
+
+    #[builder(setter(into))]
+    pub blocks: Vec<blocks::ModalBlock>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub close: Option<composition::PlainText>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub submit: Option<composition::PlainText>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub private_metadata: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub callback_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    pub clear_on_close: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    pub notify_on_close: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub external_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    pub submit_disabled: Option<bool>,
+}
+
+/// [https://api.slack.com/reference/surfaces/views#home](https://api.slack.com/reference/surfaces/views#home)
+///
+/// Create a builder with [`HomeTab::builder()`]
+///
+/// Available fields:
+///
+/// - `.blocks(`[`Into`]`<`[`Vec`]`<`[`HomeTabBlock`](blocks::HomeTabBlock)`>>)`
+///   - required
+/// - `.private_metadata(`[`Into`]`<`[`String`]`>)` - optional
+/// - `.callback_id(`[`Into`]`<`[`String`]`>)` - optional
+/// - `.external_id(`[`Into`]`<`[`String`]`>)` - optional
+///
+/// Finalise the builder with `.build()` to retrieve a [`HomeTab`]
+///
+/// ```
+/// # use assert_json_diff::assert_json_eq;
+/// # use serde_json::json;
+/// # use slack_lib::block_kit::blocks::*;
+/// # use slack_lib::block_kit::views::*;
+/// let expected = json!({
+///   "type": "home",
+///   "blocks": [
+///     {
+///       "type": "header",
+///       "text": {
+///         "type": "plain_text",
+///         "text": "Welcome home"
+///       }
+///     }
+///   ]
+/// });
+///
+/// let home = HomeTab::builder()
+///     .blocks(vec![Header::builder().text("Welcome home").build().into()])
+///     .build();
+///
+/// let output = serde_json::to_value(&home).unwrap();
+/// assert_json_eq!(expected, output);
+/// ```
+#[impl_for(View)]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
+pub struct HomeTab {
+    #[serde(rename = "type")]
+    #[builder(setter(skip), default = String::from("home"))]
+    block_type: String,
+
+    #[builder(setter(into))]
+    pub blocks: Vec<blocks::HomeTabBlock>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub private_metadata: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub callback_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub external_id: Option<String>,
+}
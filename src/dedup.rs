@@ -0,0 +1,324 @@
+//! [https://api.slack.com/apis/connections/events-api#retries](https://api.slack.com/apis/connections/events-api#retries)
+//!
+//! Slack retries an event (up to three times) when the app doesn't respond
+//! with a 2xx within three seconds, and Socket Mode redelivers anything that
+//! isn't acked in time. A [`Deduplicator`] remembers which events have been
+//! seen, so each is only processed once.
+//!
+//! Keys are recorded as soon as they're checked, before the event is
+//! handled, so a retry that arrives mid-handling is still caught. If handling
+//! fails, [`release`](Deduplicator::release) the key so Slack's next retry is
+//! processed rather than dropped.
+//!
+//! ```
+//! # use slack_lib::dedup::*;
+//! # use reqwest::header::HeaderMap;
+//! let dedup = Deduplicator::new().ack_http_timeout_retries(true);
+//!
+//! assert_eq!(dedup.check("Ev0PV52K21", None), Decision::Process);
+//! assert_eq!(dedup.check("Ev0PV52K21", None), Decision::Duplicate);
+//!
+//! // A retry because the first attempt took longer than three seconds. It's
+//! // probably still being processed, so just say it's been received.
+//! let mut headers = HeaderMap::new();
+//! headers.insert("x-slack-retry-num", "1".parse().unwrap());
+//! headers.insert("x-slack-retry-reason", "http_timeout".parse().unwrap());
+//!
+//! let retry = Retry::from_headers(&headers).unwrap();
+//! assert_eq!(retry.num, 1);
+//! assert_eq!(dedup.check("Ev0PV52K22", Some(&retry)), Decision::AckOnly);
+//! ```
+
+use crate::events::EventCallback;
+use crate::socket_mode::{Request, RequestPayload};
+use reqwest::header::HeaderMap;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+pub const RETRY_NUM_HEADER: &str = "x-slack-retry-num";
+pub const RETRY_REASON_HEADER: &str = "x-slack-retry-reason";
+
+/// What to do with an event
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decision {
+    /// Not seen before, so handle it
+    Process,
+    /// Already seen. Acknowledge it, but don't handle it again.
+    Duplicate,
+    /// A retry caused by `http_timeout`. Acknowledge it without handling it,
+    /// since the original is (most likely) still being handled.
+    AckOnly,
+}
+
+/// Why, and how many times, Slack has retried a request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Retry {
+    pub num: u32,
+    /// e.g. `http_timeout`, `http_error`, `connection_failed` or, for Socket
+    /// Mode, `timeout`
+    pub reason: String,
+}
+
+impl Retry {
+    /// Read `X-Slack-Retry-Num` and `X-Slack-Retry-Reason`. `None` if this
+    /// is the first attempt.
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let num = headers
+            .get(RETRY_NUM_HEADER)?
+            .to_str()
+            .ok()?
+            .trim()
+            .parse()
+            .ok()?;
+        let reason = headers
+            .get(RETRY_REASON_HEADER)
+            .and_then(|reason| reason.to_str().ok())
+            .unwrap_or_default()
+            .to_owned();
+
+        Some(Retry { num, reason })
+    }
+
+    pub fn is_http_timeout(&self) -> bool {
+        self.reason == "http_timeout"
+    }
+}
+
+/// Somewhere to remember which keys have been seen. Implement this to share
+/// the record between instances of an app, e.g. in Redis.
+pub trait DedupStore: Send + Sync {
+    /// Record `key`, returning `true` if it hadn't been seen before
+    fn insert(&self, key: &str) -> bool;
+
+    /// Forget `key`, so it counts as new next time
+    fn remove(&self, key: &str);
+}
+
+/// An in-memory [`DedupStore`] that forgets keys after `ttl`, or once it's
+/// holding `capacity` keys, whichever comes first. Keys are dropped least
+/// recently seen first.
+#[derive(Debug)]
+pub struct MemoryStore {
+    capacity: usize,
+    ttl: Duration,
+    inner: Mutex<MemoryStoreInner>,
+}
+
+#[derive(Debug, Default)]
+struct MemoryStoreInner {
+    entries: HashMap<String, Entry>,
+    /// Keys in the order they were last seen. A key may appear more than
+    /// once; only the entry matching its current `generation` counts.
+    order: VecDeque<(String, u64)>,
+    next_generation: u64,
+}
+
+#[derive(Debug)]
+struct Entry {
+    seen: Instant,
+    generation: u64,
+}
+
+impl MemoryStore {
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            capacity,
+            ttl,
+            inner: Mutex::new(MemoryStoreInner::default()),
+        }
+    }
+}
+
+/// Holds 10,000 keys for an hour, which covers Slack's retry schedule
+impl Default for MemoryStore {
+    fn default() -> Self {
+        Self::new(10_000, Duration::from_secs(60 * 60))
+    }
+}
+
+impl DedupStore for MemoryStore {
+    fn insert(&self, key: &str) -> bool {
+        let now = Instant::now();
+        let mut inner = self.inner.lock().unwrap_or_else(|err| err.into_inner());
+        let MemoryStoreInner {
+            entries,
+            order,
+            next_generation,
+        } = &mut *inner;
+
+        // Drop expired (and superseded) keys from the front
+        while let Some((key, generation)) = order.front() {
+            match entries.get(key) {
+                Some(entry) if entry.generation == *generation => {
+                    if now.duration_since(entry.seen) < self.ttl {
+                        break;
+                    }
+                    entries.remove(key);
+                }
+                _ => {}
+            }
+            order.pop_front();
+        }
+
+        let generation = *next_generation;
+        *next_generation += 1;
+        let is_new = entries
+            .insert(
+                key.to_owned(),
+                Entry {
+                    seen: now,
+                    generation,
+                },
+            )
+            .is_none();
+        order.push_back((key.to_owned(), generation));
+
+        while entries.len() > self.capacity {
+            match order.pop_front() {
+                Some((key, generation)) => {
+                    if entries.get(&key).map(|entry| entry.generation) == Some(generation) {
+                        entries.remove(&key);
+                    }
+                }
+                None => break,
+            }
+        }
+
+        is_new
+    }
+
+    fn remove(&self, key: &str) {
+        let mut inner = self.inner.lock().unwrap_or_else(|err| err.into_inner());
+        // Its place in `order` is skipped over once the entry is gone
+        inner.entries.remove(key);
+    }
+}
+
+/// Decides whether each event should be processed
+///
+/// ```
+/// # use slack_lib::dedup::*;
+/// # use std::time::Duration;
+/// let dedup = Deduplicator::with_store(MemoryStore::new(2, Duration::from_secs(60)));
+///
+/// assert_eq!(dedup.check("a", None), Decision::Process);
+/// assert_eq!(dedup.check("b", None), Decision::Process);
+/// assert_eq!(dedup.check("a", None), Decision::Duplicate);
+///
+/// // `b` was seen least recently, so it's dropped to make room
+/// assert_eq!(dedup.check("c", None), Decision::Process);
+/// assert_eq!(dedup.check("b", None), Decision::Process);
+/// ```
+#[derive(Debug)]
+pub struct Deduplicator<S = MemoryStore> {
+    store: S,
+    ack_http_timeout_retries: bool,
+}
+
+impl Deduplicator {
+    /// Uses the default [`MemoryStore`]
+    pub fn new() -> Self {
+        Self::with_store(MemoryStore::default())
+    }
+}
+
+impl Default for Deduplicator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: DedupStore> Deduplicator<S> {
+    pub fn with_store(store: S) -> Self {
+        Self {
+            store,
+            ack_http_timeout_retries: false,
+        }
+    }
+
+    /// Answer retries caused by `http_timeout` with [`Decision::AckOnly`],
+    /// rather than checking the store. Handy when events are handled in the
+    /// background and may not have been recorded yet. Off by default.
+    pub fn ack_http_timeout_retries(mut self, enabled: bool) -> Self {
+        self.ack_http_timeout_retries = enabled;
+        self
+    }
+
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Check a key (an `event_id` or `envelope_id`). The key is recorded
+    /// straight away, so call [`release`](Self::release) if handling it
+    /// fails.
+    pub fn check(&self, key: &str, retry: Option<&Retry>) -> Decision {
+        if self.ack_http_timeout_retries && retry.is_some_and(Retry::is_http_timeout) {
+            return Decision::AckOnly;
+        }
+
+        if self.store.insert(key) {
+            Decision::Process
+        } else {
+            Decision::Duplicate
+        }
+    }
+
+    /// Check an event delivered to a request URL, using its `event_id` and
+    /// the retry headers
+    pub fn check_event(&self, callback: &EventCallback, headers: &HeaderMap) -> Decision {
+        self.check(&callback.event_id, Retry::from_headers(headers).as_ref())
+    }
+
+    /// Check a request delivered over Socket Mode. Events are keyed on their
+    /// `event_id` (which stays the same however they're delivered), and
+    /// everything else on the `envelope_id`.
+    pub fn check_request(&self, request: &Request) -> Decision {
+        let retry = request.retry_reason.as_ref().map(|reason| Retry {
+            num: request.retry_attempt,
+            reason: reason.clone(),
+        });
+
+        self.check(request_key(request), retry.as_ref())
+    }
+
+    /// Forget a key, e.g. because handling the event failed, so that Slack's
+    /// retry is processed rather than treated as a duplicate
+    ///
+    /// ```
+    /// # use slack_lib::dedup::*;
+    /// let dedup = Deduplicator::new();
+    ///
+    /// assert_eq!(dedup.check("Ev0PV52K21", None), Decision::Process);
+    /// // Handling failed, and Slack will retry with `http_error`
+    /// dedup.release("Ev0PV52K21");
+    ///
+    /// let retry = Retry {
+    ///     num: 1,
+    ///     reason: String::from("http_error"),
+    /// };
+    /// assert_eq!(dedup.check("Ev0PV52K21", Some(&retry)), Decision::Process);
+    /// ```
+    pub fn release(&self, key: &str) {
+        self.store.remove(key);
+    }
+
+    /// Release an event checked with [`check_event`](Self::check_event)
+    pub fn release_event(&self, callback: &EventCallback) {
+        self.release(&callback.event_id);
+    }
+
+    /// Release a request checked with [`check_request`](Self::check_request)
+    pub fn release_request(&self, request: &Request) {
+        self.release(request_key(request));
+    }
+}
+
+/// Events are keyed on their `event_id`, and everything else on the
+/// `envelope_id`
+fn request_key(request: &Request) -> &str {
+    match &request.payload {
+        RequestPayload::EventsApi(callback) => &callback.event_id,
+        _ => &request.envelope_id,
+    }
+}
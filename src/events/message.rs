@@ -0,0 +1,205 @@
+//! The [`Message`] type, shared by `message` and `app_mention` events and by
+//! anything else that hands back a whole message.
+
+use crate::block_kit::blocks::MessageBlock;
+use crate::web_api::files::File;
+use crate::web_api::reactions::Reaction;
+
+/// [https://api.slack.com/events/message#subtypes](https://api.slack.com/events/message#subtypes)
+///
+/// Anything Slack adds after this list was written ends up as `Unknown`.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MessageSubtype {
+    AssistantAppThread,
+    BotMessage,
+    ChannelArchive,
+    ChannelConvertToPrivate,
+    ChannelConvertToPublic,
+    ChannelJoin,
+    ChannelLeave,
+    ChannelName,
+    ChannelPostingPermissions,
+    ChannelPurpose,
+    ChannelTopic,
+    ChannelUnarchive,
+    EkmAccessDenied,
+    FileShare,
+    GroupArchive,
+    GroupJoin,
+    GroupLeave,
+    GroupName,
+    GroupPurpose,
+    GroupTopic,
+    GroupUnarchive,
+    HuddleThread,
+    MeMessage,
+    MessageChanged,
+    MessageDeleted,
+    MessageReplied,
+    PinnedItem,
+    ReminderAdd,
+    ThreadBroadcast,
+    UnpinnedItem,
+    #[serde(other)]
+    Unknown,
+}
+
+/// When, and by whom, a message was last edited
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct Edited {
+    pub user: String,
+    pub ts: String,
+}
+
+/// [https://api.slack.com/events/message](https://api.slack.com/events/message)
+///
+/// Which fields are set depends on the `subtype`. For instance, a
+/// `message_changed` message carries the new version in `message` and the old
+/// one in `previous_message`, while `channel_name` sets `name` and `old_name`.
+///
+/// `blocks` are parsed into [`MessageBlock`]s. Slack sends some blocks that
+/// can't be represented yet (and may add more at any time), so blocks that
+/// don't parse are dropped rather than failing the whole message.
+///
+/// ```
+/// # use slack_lib::block_kit::blocks::MessageBlock;
+/// # use slack_lib::events::message::*;
+/// let message: Message = serde_json::from_value(serde_json::json!({
+///   "type": "message",
+///   "channel": "C123ABC456",
+///   "user": "U123ABC456",
+///   "text": "Deploy finished",
+///   "ts": "1355517523.000005",
+///   "blocks": [
+///     {
+///       "type": "section",
+///       "text": { "type": "mrkdwn", "text": "Deploy *finished*" }
+///     },
+///     { "type": "something_new" }
+///   ]
+/// }))
+/// .unwrap();
+///
+/// assert_eq!(message.subtype, None);
+/// assert_eq!(message.blocks.len(), 1);
+/// assert!(matches!(message.blocks[0], MessageBlock::Section(_)));
+///
+/// let edit: Message = serde_json::from_value(serde_json::json!({
+///   "type": "message",
+///   "subtype": "message_changed",
+///   "channel": "C123ABC456",
+///   "ts": "1358878755.000001",
+///   "hidden": true,
+///   "message": {
+///     "type": "message",
+///     "user": "U123ABC456",
+///     "text": "Hello, world!",
+///     "ts": "1355517523.000005",
+///     "edited": { "user": "U123ABC456", "ts": "1358878755.000001" }
+///   }
+/// }))
+/// .unwrap();
+///
+/// assert_eq!(edit.subtype, Some(MessageSubtype::MessageChanged));
+/// assert_eq!(edit.message.unwrap().text, "Hello, world!");
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct Message {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subtype: Option<MessageSubtype>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    /// `channel`, `group`, `im`, `mpim` or `app_home`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bot_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<String>,
+    #[serde(default)]
+    pub text: String,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_blocks",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub blocks: Vec<MessageBlock>,
+    /// Legacy attachments
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub files: Vec<File>,
+    #[serde(default)]
+    pub ts: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event_ts: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thread_ts: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_user_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reply_count: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_msg_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edited: Option<Edited>,
+    #[serde(default)]
+    pub hidden: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reactions: Vec<Reaction>,
+    /// The new message for `message_changed`, or the parent for
+    /// `message_replied`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<Box<Message>>,
+    /// The old message for `message_changed` and `message_deleted`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_message: Option<Box<Message>>,
+    /// Set for `message_deleted`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_ts: Option<String>,
+    /// Set for `channel_topic` and `group_topic`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub topic: Option<String>,
+    /// Set for `channel_purpose` and `group_purpose`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub purpose: Option<String>,
+    /// Set for `channel_name` and `group_name`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub old_name: Option<String>,
+    /// Set for `channel_join` when someone else added the user
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inviter: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+}
+
+impl Message {
+    /// The timestamp of the thread this message belongs to, or its own if it
+    /// starts one. Reply with this as `thread_ts` to keep things threaded.
+    pub fn thread_root(&self) -> &str {
+        self.thread_ts.as_deref().unwrap_or(&self.ts)
+    }
+}
+
+/// Parses each block on its own, dropping any that can't be represented
+fn deserialize_blocks<'de, D>(deserializer: D) -> Result<Vec<MessageBlock>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let blocks: Option<Vec<serde_json::Value>> = serde::Deserialize::deserialize(deserializer)?;
+
+    Ok(blocks
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|block| serde_json::from_value(block).ok())
+        .collect())
+}
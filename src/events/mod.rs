@@ -0,0 +1,424 @@
+//! [https://api.slack.com/apis/connections/events-api](https://api.slack.com/apis/connections/events-api)
+//!
+//! Everything Slack POSTs to an Events API request URL is an
+//! [`EventEnvelope`]. Most of the time, that's an
+//! [`EventCallback`] wrapping one [`Event`].
+//!
+//! ```
+//! # use slack_lib::events::*;
+//! let body = r#"{
+//!   "token": "XXYYZZ",
+//!   "team_id": "T123ABC456",
+//!   "api_app_id": "A123ABC456",
+//!   "event": {
+//!     "type": "app_mention",
+//!     "user": "U123ABC456",
+//!     "text": "<@U0LAN0Z89> is it everything a river should be?",
+//!     "ts": "1515449522.000016",
+//!     "channel": "C123ABC456",
+//!     "event_ts": "1515449522000016"
+//!   },
+//!   "type": "event_callback",
+//!   "event_id": "Ev123ABC456",
+//!   "event_time": 1515449522000016,
+//!   "authorizations": [
+//!     {
+//!       "enterprise_id": null,
+//!       "team_id": "T123ABC456",
+//!       "user_id": "U0LAN0Z89",
+//!       "is_bot": true,
+//!       "is_enterprise_install": false
+//!     }
+//!   ]
+//! }"#;
+//!
+//! match serde_json::from_str(body).unwrap() {
+//!     EventEnvelope::EventCallback(callback) => match callback.event {
+//!         Event::AppMention(message) => assert_eq!(message.user.unwrap(), "U123ABC456"),
+//!         _ => panic!("expected an app_mention"),
+//!     },
+//!     _ => panic!("expected an event_callback"),
+//! }
+//! ```
+
+pub mod message;
+
+use crate::web_api::conversations::Conversation;
+use crate::web_api::users::User;
+use crate::web_api::views::RenderedView;
+use message::Message;
+
+/// The outermost layer of everything sent to a request URL
+///
+/// `url_verification` is only sent while configuring the request URL. Respond
+/// with the `challenge`:
+///
+/// ```
+/// # use slack_lib::events::*;
+/// let envelope: EventEnvelope = serde_json::from_value(serde_json::json!({
+///   "token": "Jhj5dZrVaK7ZwHHjRyZWjbDl",
+///   "challenge": "3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P",
+///   "type": "url_verification"
+/// }))
+/// .unwrap();
+///
+/// match envelope {
+///     EventEnvelope::UrlVerification { challenge, .. } => {
+///         assert_eq!(challenge, "3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P")
+///     }
+///     _ => panic!("expected url_verification"),
+/// }
+/// ```
+#[allow(clippy::large_enum_variant)]
+#[derive(Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EventEnvelope {
+    UrlVerification {
+        #[serde(default)]
+        token: String,
+        challenge: String,
+    },
+    EventCallback(EventCallback),
+    /// Sent when the app is being sent more than 30,000 events an hour for a
+    /// workspace. Events will be dropped until the next minute.
+    AppRateLimited {
+        #[serde(default)]
+        token: String,
+        team_id: String,
+        minute_rate_limited: i64,
+        api_app_id: String,
+    },
+}
+
+/// [https://api.slack.com/apis/connections/events-api#callback-field](https://api.slack.com/apis/connections/events-api#callback-field)
+#[derive(Deserialize, Debug, Clone)]
+pub struct EventCallback {
+    /// Deprecated in favour of request signing
+    #[serde(default)]
+    pub token: String,
+    pub team_id: String,
+    pub api_app_id: String,
+    pub event: Event,
+    /// Unique across all workspaces, and the same for retries of an event
+    pub event_id: String,
+    pub event_time: i64,
+    #[serde(default)]
+    pub authorizations: Vec<Authorization>,
+    #[serde(default)]
+    pub is_ext_shared_channel: bool,
+    #[serde(default)]
+    pub event_context: Option<String>,
+    #[serde(default)]
+    pub enterprise_id: Option<String>,
+    #[serde(default)]
+    pub context_team_id: Option<String>,
+    #[serde(default)]
+    pub context_enterprise_id: Option<String>,
+}
+
+/// One of the installations an event is visible to
+#[derive(Deserialize, Debug, Clone)]
+pub struct Authorization {
+    #[serde(default)]
+    pub enterprise_id: Option<String>,
+    #[serde(default)]
+    pub team_id: Option<String>,
+    pub user_id: String,
+    #[serde(default)]
+    pub is_bot: bool,
+    #[serde(default)]
+    pub is_enterprise_install: bool,
+}
+
+/// [https://api.slack.com/events](https://api.slack.com/events)
+///
+/// Event types that aren't modelled are kept as `Unknown`, so subscribing to
+/// something new won't break deserialising the envelope.
+///
+/// ```
+/// # use slack_lib::events::*;
+/// let event: Event = serde_json::from_value(serde_json::json!({
+///   "type": "reaction_added",
+///   "user": "U123ABC456",
+///   "reaction": "thumbsup",
+///   "item_user": "U222222222",
+///   "item": { "type": "message", "channel": "C123ABC456", "ts": "1360782400.498405" },
+///   "event_ts": "1360782804.083113"
+/// }))
+/// .unwrap();
+///
+/// match event {
+///     Event::ReactionAdded(reaction) => {
+///         assert_eq!(reaction.reaction, "thumbsup");
+///         assert!(matches!(reaction.item, ReactedItem::Message { .. }));
+///     }
+///     _ => panic!("expected reaction_added"),
+/// }
+///
+/// let event: Event = serde_json::from_value(serde_json::json!({
+///   "type": "dnd_updated_user",
+///   "user": "U123ABC456"
+/// }))
+/// .unwrap();
+///
+/// assert_eq!(event.event_type(), "dnd_updated_user");
+/// assert!(matches!(event, Event::Unknown(_)));
+/// ```
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone)]
+pub enum Event {
+    /// [https://api.slack.com/events/message](https://api.slack.com/events/message)
+    Message(Message),
+    /// [https://api.slack.com/events/app_mention](https://api.slack.com/events/app_mention)
+    AppMention(Message),
+    /// [https://api.slack.com/events/reaction_added](https://api.slack.com/events/reaction_added)
+    ReactionAdded(ReactionEvent),
+    /// [https://api.slack.com/events/reaction_removed](https://api.slack.com/events/reaction_removed)
+    ReactionRemoved(ReactionEvent),
+    /// [https://api.slack.com/events/member_joined_channel](https://api.slack.com/events/member_joined_channel)
+    MemberJoinedChannel(MemberJoinedChannel),
+    /// [https://api.slack.com/events/channel_created](https://api.slack.com/events/channel_created)
+    ChannelCreated(ChannelEvent),
+    /// [https://api.slack.com/events/channel_rename](https://api.slack.com/events/channel_rename)
+    ChannelRename(ChannelEvent),
+    /// [https://api.slack.com/events/channel_archive](https://api.slack.com/events/channel_archive)
+    ChannelArchive(ChannelArchive),
+    /// [https://api.slack.com/events/app_home_opened](https://api.slack.com/events/app_home_opened)
+    AppHomeOpened(AppHomeOpened),
+    /// [https://api.slack.com/events/team_join](https://api.slack.com/events/team_join)
+    TeamJoin(UserEvent),
+    /// [https://api.slack.com/events/user_change](https://api.slack.com/events/user_change)
+    UserChange(UserEvent),
+    /// [https://api.slack.com/events/file_shared](https://api.slack.com/events/file_shared)
+    FileShared(FileShared),
+    /// [https://api.slack.com/events/link_shared](https://api.slack.com/events/link_shared)
+    LinkShared(LinkShared),
+    /// Any other event, as it was sent
+    Unknown(serde_json::Value),
+}
+
+impl Event {
+    /// The `type` of the event, including for `Unknown` events
+    pub fn event_type(&self) -> &str {
+        match self {
+            Event::Message(_) => "message",
+            Event::AppMention(_) => "app_mention",
+            Event::ReactionAdded(_) => "reaction_added",
+            Event::ReactionRemoved(_) => "reaction_removed",
+            Event::MemberJoinedChannel(_) => "member_joined_channel",
+            Event::ChannelCreated(_) => "channel_created",
+            Event::ChannelRename(_) => "channel_rename",
+            Event::ChannelArchive(_) => "channel_archive",
+            Event::AppHomeOpened(_) => "app_home_opened",
+            Event::TeamJoin(_) => "team_join",
+            Event::UserChange(_) => "user_change",
+            Event::FileShared(_) => "file_shared",
+            Event::LinkShared(_) => "link_shared",
+            Event::Unknown(value) => value.get("type").and_then(|t| t.as_str()).unwrap_or(""),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Event {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let kind = match value.get("type").and_then(|kind| kind.as_str()) {
+            Some(kind) => kind.to_owned(),
+            None => return Err(D::Error::missing_field("type")),
+        };
+
+        let event = match kind.as_str() {
+            "message" => serde_json::from_value(value).map(Event::Message),
+            "app_mention" => serde_json::from_value(value).map(Event::AppMention),
+            "reaction_added" => serde_json::from_value(value).map(Event::ReactionAdded),
+            "reaction_removed" => serde_json::from_value(value).map(Event::ReactionRemoved),
+            "member_joined_channel" => {
+                serde_json::from_value(value).map(Event::MemberJoinedChannel)
+            }
+            "channel_created" => serde_json::from_value(value).map(Event::ChannelCreated),
+            "channel_rename" => serde_json::from_value(value).map(Event::ChannelRename),
+            "channel_archive" => serde_json::from_value(value).map(Event::ChannelArchive),
+            "app_home_opened" => serde_json::from_value(value).map(Event::AppHomeOpened),
+            "team_join" => serde_json::from_value(value).map(Event::TeamJoin),
+            "user_change" => serde_json::from_value(value).map(Event::UserChange),
+            "file_shared" => serde_json::from_value(value).map(Event::FileShared),
+            "link_shared" => serde_json::from_value(value).map(Event::LinkShared),
+            _ => Ok(Event::Unknown(value)),
+        };
+
+        event.map_err(D::Error::custom)
+    }
+}
+
+/// Shared by `reaction_added` and `reaction_removed`
+#[derive(Deserialize, Debug, Clone)]
+pub struct ReactionEvent {
+    pub user: String,
+    /// Emoji name without colons
+    pub reaction: String,
+    /// Who wrote the item that was reacted to
+    #[serde(default)]
+    pub item_user: Option<String>,
+    pub item: ReactedItem,
+    pub event_ts: String,
+}
+
+/// What a reaction event points to. Unlike
+/// [`reactions::Item`](crate::web_api::reactions::Item), this is only a
+/// reference to the item.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ReactedItem {
+    Message { channel: String, ts: String },
+    File { file: String },
+    FileComment { file: String, file_comment: String },
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct MemberJoinedChannel {
+    pub user: String,
+    pub channel: String,
+    /// `C` for public channels, `G` for private ones
+    #[serde(default)]
+    pub channel_type: Option<String>,
+    #[serde(default)]
+    pub team: Option<String>,
+    /// Unset if the user joined by themselves
+    #[serde(default)]
+    pub inviter: Option<String>,
+    #[serde(default)]
+    pub event_ts: Option<String>,
+}
+
+/// Shared by `channel_created` and `channel_rename`
+#[derive(Deserialize, Debug, Clone)]
+pub struct ChannelEvent {
+    pub channel: Conversation,
+    #[serde(default)]
+    pub event_ts: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ChannelArchive {
+    pub channel: String,
+    pub user: String,
+    #[serde(default)]
+    pub event_ts: Option<String>,
+}
+
+/// ```
+/// # use slack_lib::block_kit::views::View;
+/// # use slack_lib::events::*;
+/// let event: Event = serde_json::from_value(serde_json::json!({
+///   "type": "app_home_opened",
+///   "user": "U123ABC456",
+///   "channel": "D123ABC456",
+///   "event_ts": "1515449522000016",
+///   "tab": "home",
+///   "view": {
+///     "id": "V123ABC456",
+///     "team_id": "T123ABC456",
+///     "type": "home",
+///     "blocks": [],
+///     "private_metadata": "",
+///     "callback_id": "",
+///     "hash": "1231232323.12321312",
+///     "app_id": "A123ABC456",
+///     "external_id": "",
+///     "bot_id": "B123ABC456"
+///   }
+/// }))
+/// .unwrap();
+///
+/// match event {
+///     Event::AppHomeOpened(opened) => {
+///         assert!(matches!(opened.view.unwrap().view, View::HomeTab(_)))
+///     }
+///     _ => panic!("expected app_home_opened"),
+/// }
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct AppHomeOpened {
+    pub user: String,
+    pub channel: String,
+    /// `home` or `messages`
+    pub tab: String,
+    /// Only set once a home tab has been published for the user
+    #[serde(default)]
+    pub view: Option<RenderedView>,
+    pub event_ts: String,
+}
+
+/// Shared by `team_join` and `user_change`
+#[derive(Deserialize, Debug, Clone)]
+pub struct UserEvent {
+    pub user: User,
+    #[serde(default)]
+    pub cache_ts: Option<i64>,
+    #[serde(default)]
+    pub event_ts: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct FileShared {
+    pub file_id: String,
+    pub user_id: String,
+    #[serde(default)]
+    pub channel_id: Option<String>,
+    pub event_ts: String,
+}
+
+/// ```
+/// # use slack_lib::events::*;
+/// let event: Event = serde_json::from_value(serde_json::json!({
+///   "type": "link_shared",
+///   "channel": "Cxxxxxx",
+///   "is_bot_user_member": true,
+///   "user": "Uxxxxxxx",
+///   "message_ts": "123456789.9875",
+///   "unfurl_id": "C123456.123456789.987501.1b90fa1278528ce6e2f6c5c2bfa1abc9a41d57d02b29d173f40399c9ffdecf4b",
+///   "thread_ts": "123456621.1855",
+///   "event_ts": "123456621.1855",
+///   "source": "conversations_history",
+///   "links": [
+///     { "domain": "example.com", "url": "https://example.com/12345" },
+///     { "domain": "example.com", "url": "https://example.com/67890" }
+///   ]
+/// }))
+/// .unwrap();
+///
+/// match event {
+///     Event::LinkShared(shared) => assert_eq!(shared.links[1].url, "https://example.com/67890"),
+///     _ => panic!("expected link_shared"),
+/// }
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct LinkShared {
+    pub channel: String,
+    pub user: String,
+    pub message_ts: String,
+    #[serde(default)]
+    pub thread_ts: Option<String>,
+    pub links: Vec<SharedLink>,
+    /// Pass this to `chat.unfurl` instead of `channel` and `ts` when the link
+    /// was shared in the composer
+    #[serde(default)]
+    pub unfurl_id: Option<String>,
+    /// `composer` or `conversations_history`
+    #[serde(default)]
+    pub source: Option<String>,
+    #[serde(default)]
+    pub is_bot_user_member: bool,
+    pub event_ts: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct SharedLink {
+    pub domain: String,
+    pub url: String,
+}
@@ -0,0 +1,333 @@
+//! [https://api.slack.com/messaging/webhooks](https://api.slack.com/messaging/webhooks)
+//!
+//! An incoming webhook posts to a single channel, chosen when the webhook was
+//! created, and needs no token. Slack answers with plain text: `ok`, or an
+//! error code like `no_text`, which [`IncomingWebhook::send`] turns into an
+//! [`Error`].
+//!
+//! ```no_run
+//! # use slack_lib::incoming_webhook::*;
+//! # async fn run() -> Result<(), Error> {
+//! let webhook = IncomingWebhook::new("https://hooks.slack.com/services/T000/B000/XXXX");
+//!
+//! webhook
+//!     .send(&WebhookMessage::builder().content("Disk usage is at 95%").build())
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::slash_commands::MessageContent;
+use std::fmt;
+
+/// Create a builder with [`WebhookMessage::builder()`]
+///
+/// Available fields:
+///
+/// - `.content(`[`Into`]`<`[`MessageContent`]`>)` - required
+/// - `.attachments(`[`Into`]`<`[`Vec`]`<`[`Attachment`]`>>)` - optional,
+///   shown below the content
+/// - `.thread_ts(`[`Into`]`<`[`String`]`>)` - optional
+/// - `.mrkdwn(`[`bool`]`)` - optional, whether to format `text`. Defaults to
+///   true.
+/// - `.unfurl_links(`[`bool`]`)` - optional
+/// - `.unfurl_media(`[`bool`]`)` - optional
+///
+/// Finalise the builder with `.build()` to retrieve a [`WebhookMessage`]
+///
+/// ```
+/// # use assert_json_diff::assert_json_eq;
+/// # use serde_json::json;
+/// # use slack_lib::block_kit::{blocks::*, composition::*};
+/// # use slack_lib::incoming_webhook::*;
+/// # use slack_lib::slash_commands::MessageContent;
+/// let message = WebhookMessage::builder()
+///     .content(MessageContent::Blocks {
+///         blocks: vec![Section::builder()
+///             .text(Markdown::from("*Build failed* on `main`"))
+///             .build()
+///             .into()],
+///         text: Some(String::from("Build failed")),
+///     })
+///     .attachments(vec![Attachment::builder()
+///         .color("danger")
+///         .fields(vec![AttachmentField::builder()
+///             .title("Stage")
+///             .value("test")
+///             .short(true)
+///             .build()])
+///         .build()])
+///     .build();
+///
+/// let expected = json!({
+///   "text": "Build failed",
+///   "blocks": [{
+///     "type": "section",
+///     "text": { "type": "mrkdwn", "text": "*Build failed* on `main`" }
+///   }],
+///   "attachments": [{
+///     "color": "danger",
+///     "fields": [{ "title": "Stage", "value": "test", "short": true }]
+///   }]
+/// });
+///
+/// let output = serde_json::to_value(&message).unwrap();
+/// assert_json_eq!(expected, output);
+/// ```
+#[derive(TypedBuilder, Serialize, Debug, Clone)]
+pub struct WebhookMessage {
+    #[serde(flatten)]
+    #[builder(setter(into))]
+    pub content: MessageContent,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub attachments: Option<Vec<Attachment>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub thread_ts: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    pub mrkdwn: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    pub unfurl_links: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    pub unfurl_media: Option<bool>,
+}
+
+/// [https://api.slack.com/reference/messaging/attachments](https://api.slack.com/reference/messaging/attachments)
+///
+/// Legacy, but still the only way to get a coloured bar down the side of a
+/// message.
+///
+/// Create a builder with [`Attachment::builder()`]. Every field is optional.
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Attachment {
+    /// Plain text summary, for clients that can't show attachments
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub fallback: Option<String>,
+
+    /// `good`, `warning`, `danger`, or a hex colour like `#439FE0`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub color: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub pretext: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub author_name: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub author_link: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub author_icon: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub title: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub title_link: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub text: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub fields: Option<Vec<AttachmentField>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub image_url: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub thumb_url: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub footer: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub footer_icon: Option<String>,
+
+    /// Unix timestamp, shown next to the footer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    pub ts: Option<i64>,
+
+    /// Which fields to format as mrkdwn, e.g. `text` or `pretext`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub mrkdwn_in: Option<Vec<String>>,
+}
+
+/// Create a builder with [`AttachmentField::builder()`]
+///
+/// Available fields:
+///
+/// - `.title(`[`Into`]`<`[`String`]`>)` - required
+/// - `.value(`[`Into`]`<`[`String`]`>)` - required
+/// - `.short(`[`bool`]`)` - optional, whether it can sit side by side with
+///   another field
+///
+/// Finalise the builder with `.build()` to retrieve an [`AttachmentField`]
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone, Default)]
+pub struct AttachmentField {
+    #[builder(setter(into))]
+    pub title: String,
+
+    #[builder(setter(into))]
+    pub value: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    pub short: Option<bool>,
+}
+
+/// [https://api.slack.com/messaging/webhooks#handling_errors](https://api.slack.com/messaging/webhooks#handling_errors)
+#[derive(Debug)]
+pub enum Error {
+    /// The request never made it, or the response couldn't be read
+    Http(reqwest::Error),
+    /// The message couldn't be understood, e.g. malformed blocks
+    InvalidPayload,
+    /// The message had no `text` (or blocks or attachments)
+    NoText,
+    ChannelIsArchived,
+    /// The webhook's channel no longer exists
+    ChannelNotFound,
+    /// An admin has restricted posting to the channel
+    ActionProhibited,
+    PostingToGeneralChannelDenied,
+    /// More than 100 attachments
+    TooManyAttachments,
+    /// The webhook has been disabled, removed or is invalid
+    NoService,
+    RateLimited {
+        retry_after: Option<u64>,
+    },
+    /// Any other error. `error` is the body of the response.
+    Other {
+        status: u16,
+        error: String,
+    },
+}
+
+impl Error {
+    fn from_response(status: u16, body: &str) -> Self {
+        match body.trim() {
+            "invalid_payload" => Error::InvalidPayload,
+            "no_text" => Error::NoText,
+            "channel_is_archived" => Error::ChannelIsArchived,
+            "channel_not_found" => Error::ChannelNotFound,
+            "action_prohibited" => Error::ActionProhibited,
+            "posting_to_general_channel_denied" => Error::PostingToGeneralChannelDenied,
+            "too_many_attachments" => Error::TooManyAttachments,
+            "no_service" => Error::NoService,
+            error => Error::Other {
+                status,
+                error: error.to_owned(),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Http(err) => write!(f, "http error: {}", err),
+            Error::InvalidPayload => f.write_str("invalid_payload"),
+            Error::NoText => f.write_str("no_text"),
+            Error::ChannelIsArchived => f.write_str("channel_is_archived"),
+            Error::ChannelNotFound => f.write_str("channel_not_found"),
+            Error::ActionProhibited => f.write_str("action_prohibited"),
+            Error::PostingToGeneralChannelDenied => {
+                f.write_str("posting_to_general_channel_denied")
+            }
+            Error::TooManyAttachments => f.write_str("too_many_attachments"),
+            Error::NoService => f.write_str("no_service"),
+            Error::RateLimited {
+                retry_after: Some(seconds),
+            } => write!(f, "rate limited, retry after {} seconds", seconds),
+            Error::RateLimited { retry_after: None } => f.write_str("rate limited"),
+            Error::Other { status, error } => write!(f, "webhook rejected ({}): {}", status, error),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Http(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Self {
+        Error::Http(err)
+    }
+}
+
+/// Posts messages to an incoming webhook URL
+#[derive(Debug, Clone)]
+pub struct IncomingWebhook {
+    http: reqwest::Client,
+    url: String,
+}
+
+impl IncomingWebhook {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self::with_http_client(reqwest::Client::new(), url)
+    }
+
+    /// Use an existing [`reqwest::Client`], e.g. one configured with a proxy
+    pub fn with_http_client(http: reqwest::Client, url: impl Into<String>) -> Self {
+        Self {
+            http,
+            url: url.into(),
+        }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub async fn send(&self, message: &WebhookMessage) -> Result<(), Error> {
+        let response = self.http.post(&self.url).json(message).send().await?;
+        let status = response.status();
+
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            let retry_after = response
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse().ok());
+            return Err(Error::RateLimited { retry_after });
+        }
+
+        let body = response.text().await?;
+        if status.is_success() {
+            return Ok(());
+        }
+        Err(Error::from_response(status.as_u16(), &body))
+    }
+}
@@ -0,0 +1,148 @@
+//! [https://api.slack.com/reference/interaction-payloads/block-actions](https://api.slack.com/reference/interaction-payloads/block-actions)
+
+use super::{ActionValue, Channel, Enterprise, State, Team, User};
+use crate::events::message::Message;
+use crate::web_api::views::RenderedView;
+
+/// Sent when a user interacts with an element in a message, modal or home
+/// tab. Which of `message`, `view` and `channel` are set depends on where the
+/// element was; `container` always says.
+///
+/// ```
+/// # use slack_lib::block_kit::composition::OptionItem;
+/// # use slack_lib::interactivity::*;
+/// # use slack_lib::interactivity::block_actions::*;
+/// let payload: Payload = serde_json::from_value(serde_json::json!({
+///   "type": "block_actions",
+///   "team": { "id": "T9TK3CUKW", "domain": "example" },
+///   "user": { "id": "UA8RXUSPL", "username": "jtorrance", "team_id": "T9TK3CUKW" },
+///   "api_app_id": "AABA1ABCD",
+///   "token": "9s8d9as89d8as9d8as989",
+///   "container": {
+///     "type": "message",
+///     "message_ts": "1548261231.000200",
+///     "channel_id": "CBR2V3XEX",
+///     "is_ephemeral": false
+///   },
+///   "trigger_id": "12321423423.333649436676.d8c1bb837935619ccad0f624c448ffb3",
+///   "channel": { "id": "CBR2V3XEX", "name": "review-updates" },
+///   "message": {
+///     "type": "message",
+///     "user": "UAJ2RU415",
+///     "text": "Pick a pastry",
+///     "ts": "1548261231.000200"
+///   },
+///   "response_url": "https://hooks.slack.com/actions/AABA1ABCD/1232321423432/D09sSasdasdAS9091209",
+///   "actions": [
+///     {
+///       "type": "button",
+///       "action_id": "approve",
+///       "block_id": "buttons",
+///       "text": { "type": "plain_text", "text": "Approve", "emoji": true },
+///       "value": "click_me_123",
+///       "action_ts": "1548426417.840180"
+///     },
+///     {
+///       "type": "static_select",
+///       "action_id": "pastry",
+///       "block_id": "select",
+///       "selected_option": {
+///         "text": { "type": "plain_text", "text": "Croissant" },
+///         "value": "croissant"
+///       },
+///       "action_ts": "1548426417.840180"
+///     }
+///   ]
+/// }))
+/// .unwrap();
+///
+/// let actions = match payload {
+///     Payload::BlockActions(actions) => actions,
+///     _ => panic!("expected block_actions"),
+/// };
+///
+/// assert!(matches!(actions.container, Container::Message { .. }));
+/// assert_eq!(actions.message.unwrap().text, "Pick a pastry");
+///
+/// match &actions.actions[0].value {
+///     ActionValue::Button { value, .. } => assert_eq!(value.as_deref(), Some("click_me_123")),
+///     _ => panic!("expected a button"),
+/// }
+///
+/// match &actions.actions[1].value {
+///     ActionValue::StaticSelect {
+///         selected_option: Some(OptionItem::PlainTextOptionItem(option)),
+///     } => assert_eq!(option.value, "croissant"),
+///     _ => panic!("expected a static_select"),
+/// }
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct BlockActions {
+    pub user: User,
+    pub api_app_id: String,
+    /// Deprecated in favour of request signing
+    #[serde(default)]
+    pub token: String,
+    pub container: Container,
+    /// Use this to open a modal within three seconds
+    pub trigger_id: String,
+    #[serde(default)]
+    pub team: Option<Team>,
+    #[serde(default)]
+    pub enterprise: Option<Enterprise>,
+    #[serde(default)]
+    pub is_enterprise_install: bool,
+    #[serde(default)]
+    pub channel: Option<Channel>,
+    /// The message the element was in, if it was in one
+    #[serde(default)]
+    pub message: Option<Message>,
+    /// The view the element was in, if it was in one
+    #[serde(default)]
+    pub view: Option<RenderedView>,
+    /// The values of any other inputs in a message. Elements in views have
+    /// their values in `view.state` instead.
+    #[serde(default)]
+    pub state: Option<State>,
+    /// Only set for interactions in messages
+    #[serde(default)]
+    pub response_url: Option<String>,
+    pub actions: Vec<Action>,
+}
+
+/// One interaction with an element
+#[derive(Deserialize, Debug, Clone)]
+pub struct Action {
+    pub action_id: String,
+    pub block_id: String,
+    pub action_ts: String,
+    /// The type of element, along with its new value
+    #[serde(flatten)]
+    pub value: ActionValue,
+}
+
+/// Where the element that was interacted with is
+#[derive(Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Container {
+    Message {
+        message_ts: String,
+        channel_id: String,
+        #[serde(default)]
+        is_ephemeral: bool,
+        #[serde(default)]
+        thread_ts: Option<String>,
+    },
+    View {
+        view_id: String,
+    },
+    MessageAttachment {
+        message_ts: String,
+        attachment_id: i64,
+        channel_id: String,
+        #[serde(default)]
+        is_ephemeral: bool,
+    },
+    #[serde(other)]
+    Unknown,
+}
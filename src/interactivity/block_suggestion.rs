@@ -0,0 +1,174 @@
+//! [https://api.slack.com/reference/block-kit/block-elements#external_select](https://api.slack.com/reference/block-kit/block-elements#external_select)
+//!
+//! `external_select` and `multi_external_select` elements load their options
+//! from the app's options load URL as the user types. Slack sends a
+//! [`BlockSuggestion`], and the app responds with an [`OptionsResponse`] as
+//! JSON.
+
+use super::block_actions::Container;
+use super::{Channel, Enterprise, Team, User};
+use crate::block_kit::composition::{OptionGroup, PlainTextOptionItem};
+use crate::events::message::Message;
+use crate::web_api::views::RenderedView;
+use std::fmt;
+
+/// The most options (or option groups) Slack will show
+pub const MAX_OPTIONS: usize = 100;
+
+/// Sent as the user types into an external select
+///
+/// ```
+/// # use slack_lib::interactivity::*;
+/// let payload: Payload = serde_json::from_value(serde_json::json!({
+///   "type": "block_suggestion",
+///   "user": { "id": "U123ABC456", "team_id": "T123ABC456" },
+///   "container": { "type": "view", "view_id": "V123ABC456" },
+///   "api_app_id": "A123ABC456",
+///   "token": "XXXXXXXXXXXXX",
+///   "action_id": "pick_ticket",
+///   "block_id": "ticket",
+///   "value": "PROJ-12",
+///   "team": { "id": "T123ABC456", "domain": "example" }
+/// }))
+/// .unwrap();
+///
+/// match payload {
+///     Payload::BlockSuggestion(suggestion) => {
+///         assert_eq!(suggestion.action_id, "pick_ticket");
+///         assert_eq!(suggestion.value, "PROJ-12");
+///     }
+///     _ => panic!("expected block_suggestion"),
+/// }
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct BlockSuggestion {
+    pub user: User,
+    pub api_app_id: String,
+    /// Deprecated in favour of request signing
+    #[serde(default)]
+    pub token: String,
+    pub action_id: String,
+    pub block_id: String,
+    /// What the user has typed so far
+    #[serde(default)]
+    pub value: String,
+    pub container: Container,
+    #[serde(default)]
+    pub team: Option<Team>,
+    #[serde(default)]
+    pub enterprise: Option<Enterprise>,
+    #[serde(default)]
+    pub is_enterprise_install: bool,
+    #[serde(default)]
+    pub channel: Option<Channel>,
+    #[serde(default)]
+    pub message: Option<Message>,
+    #[serde(default)]
+    pub view: Option<RenderedView>,
+}
+
+/// [https://api.slack.com/reference/block-kit/block-elements#external_select](https://api.slack.com/reference/block-kit/block-elements#external_select)
+///
+/// Either a flat list of options or a list of option groups, with at most
+/// [`MAX_OPTIONS`] of either (and of options within each group).
+///
+/// ```
+/// # use assert_json_diff::assert_json_eq;
+/// # use serde_json::json;
+/// # use slack_lib::block_kit::composition::*;
+/// # use slack_lib::interactivity::block_suggestion::*;
+/// let response = OptionsResponse::options(vec![PlainTextOptionItem::builder()
+///     .text("PROJ-12: Fix the build")
+///     .value("PROJ-12")
+///     .build()])
+/// .unwrap();
+///
+/// let expected = json!({
+///   "options": [
+///     {
+///       "text": { "type": "plain_text", "text": "PROJ-12: Fix the build" },
+///       "value": "PROJ-12"
+///     }
+///   ]
+/// });
+///
+/// let output = serde_json::to_value(&response).unwrap();
+/// assert_json_eq!(expected, output);
+///
+/// let too_many = (0..101)
+///     .map(|i| {
+///         PlainTextOptionItem::builder()
+///             .text(i.to_string().as_str())
+///             .value(i.to_string())
+///             .build()
+///     })
+///     .collect();
+///
+/// assert_eq!(
+///     OptionsResponse::options(too_many).unwrap_err(),
+///     TooManyOptions { count: 101 }
+/// );
+/// ```
+#[derive(Serialize, Debug, Clone)]
+pub struct OptionsResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    options: Option<Vec<PlainTextOptionItem>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    option_groups: Option<Vec<OptionGroup>>,
+}
+
+impl OptionsResponse {
+    pub fn options(options: Vec<PlainTextOptionItem>) -> Result<Self, TooManyOptions> {
+        check_count(options.len())?;
+
+        Ok(Self {
+            options: Some(options),
+            option_groups: None,
+        })
+    }
+
+    pub fn option_groups(option_groups: Vec<OptionGroup>) -> Result<Self, TooManyOptions> {
+        check_count(option_groups.len())?;
+        for group in &option_groups {
+            check_count(group.options.len())?;
+        }
+
+        Ok(Self {
+            options: None,
+            option_groups: Some(option_groups),
+        })
+    }
+
+    /// No matches. Slack shows "No results" to the user.
+    pub fn empty() -> Self {
+        Self {
+            options: Some(vec![]),
+            option_groups: None,
+        }
+    }
+}
+
+fn check_count(count: usize) -> Result<(), TooManyOptions> {
+    if count > MAX_OPTIONS {
+        return Err(TooManyOptions { count });
+    }
+    Ok(())
+}
+
+/// More than [`MAX_OPTIONS`] options (or option groups) were given
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TooManyOptions {
+    pub count: usize,
+}
+
+impl fmt::Display for TooManyOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} options given, but at most {} are allowed",
+            self.count, MAX_OPTIONS
+        )
+    }
+}
+
+impl std::error::Error for TooManyOptions {}
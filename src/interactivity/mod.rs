@@ -0,0 +1,249 @@
+//! [https://api.slack.com/interactivity/handling#payloads](https://api.slack.com/interactivity/handling#payloads)
+//!
+//! Slack sends interactions to the app's interactivity request URL as a form
+//! body, with the JSON in a single `payload` field. Parse it with
+//! [`Payload::from_form`].
+//!
+//! ```
+//! # use slack_lib::interactivity::*;
+//! let body = "payload=%7B%22type%22%3A%22block_actions%22%2C%22user%22%3A%7B%22id%22%3A%22U123%22%7D%2C%22api_app_id%22%3A%22A123%22%2C%22trigger_id%22%3A%2212345.98765.abcd2358fdea%22%2C%22container%22%3A%7B%22type%22%3A%22view%22%2C%22view_id%22%3A%22V123%22%7D%2C%22actions%22%3A%5B%5D%7D";
+//!
+//! match Payload::from_form(body).unwrap() {
+//!     Payload::BlockActions(actions) => assert_eq!(actions.user.id, "U123"),
+//!     _ => panic!("expected block_actions"),
+//! }
+//! ```
+
+pub mod block_actions;
+pub mod block_suggestion;
+pub mod shortcuts;
+pub mod view_submission;
+
+use crate::block_kit::composition::{OptionItem, OverflowOptionItem, PlainText};
+use std::collections::HashMap;
+use std::fmt;
+
+pub use block_actions::BlockActions;
+pub use block_suggestion::BlockSuggestion;
+pub use shortcuts::{MessageAction, Shortcut};
+pub use view_submission::{ViewClosed, ViewSubmission};
+
+/// Every kind of interaction payload
+#[allow(clippy::large_enum_variant)]
+#[derive(Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Payload {
+    BlockActions(BlockActions),
+    ViewSubmission(ViewSubmission),
+    ViewClosed(ViewClosed),
+    BlockSuggestion(BlockSuggestion),
+    Shortcut(Shortcut),
+    MessageAction(MessageAction),
+    /// Any payload type that isn't modelled yet
+    #[serde(other)]
+    Unknown,
+}
+
+impl Payload {
+    /// Parse the form body of an interactivity request
+    pub fn from_form(body: &str) -> Result<Self, Error> {
+        #[derive(Deserialize)]
+        struct Form {
+            payload: String,
+        }
+
+        let form: Form = serde_urlencoded::from_str(body)?;
+        Ok(serde_json::from_str(&form.payload)?)
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    /// The body wasn't a form, or had no `payload` field
+    Form(serde_urlencoded::de::Error),
+    /// The payload wasn't JSON, or wasn't a payload Slack would send
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Form(err) => write!(f, "invalid form body: {}", err),
+            Error::Json(err) => write!(f, "invalid payload: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Form(err) => Some(err),
+            Error::Json(err) => Some(err),
+        }
+    }
+}
+
+impl From<serde_urlencoded::de::Error> for Error {
+    fn from(err: serde_urlencoded::de::Error) -> Self {
+        Error::Form(err)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::Json(err)
+    }
+}
+
+/// The user who interacted
+#[derive(Deserialize, Debug, Clone)]
+pub struct User {
+    pub id: String,
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub team_id: Option<String>,
+}
+
+/// The workspace the interaction happened in. Unset for interactions in
+/// org-wide apps that don't happen in any one workspace.
+#[derive(Deserialize, Debug, Clone)]
+pub struct Team {
+    pub id: String,
+    #[serde(default)]
+    pub domain: Option<String>,
+    #[serde(default)]
+    pub enterprise_id: Option<String>,
+    #[serde(default)]
+    pub enterprise_name: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Enterprise {
+    pub id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Channel {
+    pub id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+/// The values of the interactive elements in a view (or message), keyed by
+/// `block_id` and then `action_id`
+///
+/// ```
+/// # use slack_lib::interactivity::*;
+/// let state: State = serde_json::from_value(serde_json::json!({
+///   "values": {
+///     "title": {
+///       "title_input": { "type": "plain_text_input", "value": "Lunch" }
+///     },
+///     "when": {
+///       "date": { "type": "datepicker", "selected_date": "2019-03-04" }
+///     }
+///   }
+/// }))
+/// .unwrap();
+///
+/// match state.get("title", "title_input") {
+///     Some(ActionValue::PlainTextInput { value }) => assert_eq!(value.as_deref(), Some("Lunch")),
+///     _ => panic!("expected a plain_text_input"),
+/// }
+/// ```
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct State {
+    #[serde(default)]
+    pub values: HashMap<String, HashMap<String, ActionValue>>,
+}
+
+impl State {
+    pub fn get(&self, block_id: &str, action_id: &str) -> Option<&ActionValue> {
+        self.values.get(block_id)?.get(action_id)
+    }
+}
+
+/// The current value of one of the elements in
+/// [`block_kit::elements`](crate::block_kit::elements)
+///
+/// Unselected values are `None` (or empty), since Slack includes elements in
+/// the state whether or not the user touched them.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ActionValue {
+    Button {
+        text: PlainText,
+        #[serde(default)]
+        value: Option<String>,
+        #[serde(default)]
+        url: Option<String>,
+    },
+    Checkboxes {
+        #[serde(default)]
+        selected_options: Vec<OptionItem>,
+    },
+    Datepicker {
+        /// `YYYY-MM-DD`
+        #[serde(default)]
+        selected_date: Option<String>,
+    },
+    MultiStaticSelect {
+        #[serde(default)]
+        selected_options: Vec<OptionItem>,
+    },
+    MultiExternalSelect {
+        #[serde(default)]
+        selected_options: Vec<OptionItem>,
+    },
+    MultiUsersSelect {
+        #[serde(default)]
+        selected_users: Vec<String>,
+    },
+    MultiConversationsSelect {
+        #[serde(default)]
+        selected_conversations: Vec<String>,
+    },
+    MultiChannelsSelect {
+        #[serde(default)]
+        selected_channels: Vec<String>,
+    },
+    Overflow {
+        selected_option: OverflowOptionItem,
+    },
+    PlainTextInput {
+        #[serde(default)]
+        value: Option<String>,
+    },
+    RadioButtons {
+        #[serde(default)]
+        selected_option: Option<OptionItem>,
+    },
+    StaticSelect {
+        #[serde(default)]
+        selected_option: Option<OptionItem>,
+    },
+    ExternalSelect {
+        #[serde(default)]
+        selected_option: Option<OptionItem>,
+    },
+    UsersSelect {
+        #[serde(default)]
+        selected_user: Option<String>,
+    },
+    ConversationsSelect {
+        #[serde(default)]
+        selected_conversation: Option<String>,
+    },
+    ChannelsSelect {
+        #[serde(default)]
+        selected_channel: Option<String>,
+    },
+    /// An element that isn't modelled yet
+    #[serde(other)]
+    Unknown,
+}
@@ -0,0 +1,112 @@
+//! [https://api.slack.com/reference/interaction-payloads/shortcuts](https://api.slack.com/reference/interaction-payloads/shortcuts)
+//!
+//! Both kinds of shortcut come with a `trigger_id`, so the usual thing to do
+//! is open a modal with [`ViewsOpen`](crate::web_api::views::ViewsOpen).
+
+use super::{Channel, Enterprise, Team, User};
+use crate::events::message::Message;
+
+/// Sent when a user picks a global shortcut from the shortcuts menu or the
+/// search bar
+///
+/// ```
+/// # use slack_lib::interactivity::*;
+/// let payload: Payload = serde_json::from_value(serde_json::json!({
+///   "type": "shortcut",
+///   "token": "XXXXXXXXXXXXX",
+///   "action_ts": "1581106241.371594",
+///   "team": { "id": "TXXXXXXXX", "domain": "shortcuts-test" },
+///   "user": { "id": "UXXXXXXXXX", "username": "aman", "team_id": "TXXXXXXXX" },
+///   "api_app_id": "A123ABC456",
+///   "callback_id": "shortcut_create_task",
+///   "trigger_id": "944799105734.773906753841.38b5894552bdd4a780554ee59d1f3638"
+/// }))
+/// .unwrap();
+///
+/// match payload {
+///     Payload::Shortcut(shortcut) => assert_eq!(shortcut.callback_id, "shortcut_create_task"),
+///     _ => panic!("expected shortcut"),
+/// }
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct Shortcut {
+    pub user: User,
+    #[serde(default)]
+    pub api_app_id: Option<String>,
+    /// Deprecated in favour of request signing
+    #[serde(default)]
+    pub token: String,
+    /// The callback ID the shortcut was configured with
+    pub callback_id: String,
+    pub trigger_id: String,
+    pub action_ts: String,
+    #[serde(default)]
+    pub team: Option<Team>,
+    #[serde(default)]
+    pub enterprise: Option<Enterprise>,
+    #[serde(default)]
+    pub is_enterprise_install: bool,
+}
+
+/// Sent when a user picks a message shortcut from a message's "More actions"
+/// menu
+///
+/// ```
+/// # use slack_lib::block_kit::blocks::MessageBlock;
+/// # use slack_lib::interactivity::*;
+/// let payload: Payload = serde_json::from_value(serde_json::json!({
+///   "type": "message_action",
+///   "callback_id": "create_ticket",
+///   "trigger_id": "13345224609.8534564800.6f8ab1f53e13d0cd15f96106292d5536",
+///   "action_ts": "1548261237.000700",
+///   "team": { "id": "T123ABC456", "domain": "example" },
+///   "user": { "id": "U123ABC456", "name": "dana" },
+///   "channel": { "id": "C123ABC456", "name": "incidents" },
+///   "message_ts": "1548261231.000200",
+///   "response_url": "https://hooks.slack.com/app-actions/T0MJR11A4/21974584944/yk1S9ndf35Q1flupVG5JbpM6",
+///   "message": {
+///     "type": "message",
+///     "user": "U222222222",
+///     "ts": "1548261231.000200",
+///     "text": "The build is broken",
+///     "blocks": [
+///       {
+///         "type": "section",
+///         "text": { "type": "mrkdwn", "text": "The build is *broken*" }
+///       }
+///     ]
+///   }
+/// }))
+/// .unwrap();
+///
+/// match payload {
+///     Payload::MessageAction(action) => {
+///         assert_eq!(action.channel.id, "C123ABC456");
+///         assert_eq!(action.message.text, "The build is broken");
+///         assert!(matches!(action.message.blocks[0], MessageBlock::Section(_)));
+///     }
+///     _ => panic!("expected message_action"),
+/// }
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct MessageAction {
+    pub user: User,
+    #[serde(default)]
+    pub api_app_id: Option<String>,
+    #[serde(default)]
+    pub token: String,
+    pub callback_id: String,
+    pub trigger_id: String,
+    pub action_ts: String,
+    #[serde(default)]
+    pub team: Option<Team>,
+    #[serde(default)]
+    pub enterprise: Option<Enterprise>,
+    #[serde(default)]
+    pub is_enterprise_install: bool,
+    pub channel: Channel,
+    pub message_ts: String,
+    /// The message the shortcut was used on
+    pub message: Message,
+    pub response_url: String,
+}
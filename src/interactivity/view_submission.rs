@@ -0,0 +1,140 @@
+//! [https://api.slack.com/reference/interaction-payloads/views](https://api.slack.com/reference/interaction-payloads/views)
+
+use super::{Enterprise, Team, User};
+use crate::web_api::views::RenderedView;
+
+/// Sent when a user submits a modal. The values of its inputs are in
+/// `view.state`.
+///
+/// ```
+/// # use slack_lib::interactivity::*;
+/// let payload: Payload = serde_json::from_value(serde_json::json!({
+///   "type": "view_submission",
+///   "team": { "id": "T123ABC456", "domain": "example" },
+///   "user": { "id": "U123ABC456", "username": "dana", "team_id": "T123ABC456" },
+///   "api_app_id": "A123ABC456",
+///   "token": "XXXXXXXXXXXXX",
+///   "trigger_id": "12345.98765.abcd2358fdea",
+///   "view": {
+///     "id": "V123ABC456",
+///     "type": "modal",
+///     "title": { "type": "plain_text", "text": "Meeting Arranger" },
+///     "blocks": [],
+///     "private_metadata": "C123ABC456",
+///     "callback_id": "meeting_arranger",
+///     "state": {
+///       "values": {
+///         "channel": {
+///           "pick": {
+///             "type": "conversations_select",
+///             "selected_conversation": "C0123456"
+///           }
+///         }
+///       }
+///     },
+///     "hash": "156663117.cd33ad1f"
+///   },
+///   "response_urls": [
+///     {
+///       "block_id": "channel",
+///       "action_id": "pick",
+///       "channel_id": "C0123456",
+///       "response_url": "https://hooks.slack.com/app/T123ABC456/1234/abcd"
+///     }
+///   ]
+/// }))
+/// .unwrap();
+///
+/// let submission = match payload {
+///     Payload::ViewSubmission(submission) => submission,
+///     _ => panic!("expected view_submission"),
+/// };
+///
+/// assert_eq!(submission.view.callback_id(), "meeting_arranger");
+/// assert_eq!(submission.view.private_metadata(), "C123ABC456");
+/// assert_eq!(submission.response_urls[0].channel_id, "C0123456");
+///
+/// let state = submission.view.state.unwrap();
+/// match state.get("channel", "pick") {
+///     Some(ActionValue::ConversationsSelect { selected_conversation }) => {
+///         assert_eq!(selected_conversation.as_deref(), Some("C0123456"))
+///     }
+///     _ => panic!("expected a conversations_select"),
+/// }
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct ViewSubmission {
+    pub user: User,
+    pub api_app_id: String,
+    /// Deprecated in favour of request signing
+    #[serde(default)]
+    pub token: String,
+    /// Use this to push a new view (rather than responding with
+    /// `response_action`)
+    #[serde(default)]
+    pub trigger_id: Option<String>,
+    #[serde(default)]
+    pub team: Option<Team>,
+    #[serde(default)]
+    pub enterprise: Option<Enterprise>,
+    #[serde(default)]
+    pub is_enterprise_install: bool,
+    pub view: RenderedView,
+    /// One for each `conversations_select` or `channels_select` with
+    /// `response_url_enabled` set
+    #[serde(default)]
+    pub response_urls: Vec<ResponseUrlInfo>,
+}
+
+/// Sent when a user closes a modal that had `notify_on_close` set
+///
+/// ```
+/// # use slack_lib::interactivity::*;
+/// let payload: Payload = serde_json::from_value(serde_json::json!({
+///   "type": "view_closed",
+///   "team": { "id": "T123ABC456", "domain": "example" },
+///   "user": { "id": "U123ABC456" },
+///   "api_app_id": "A123ABC456",
+///   "view": {
+///     "id": "V123ABC456",
+///     "type": "modal",
+///     "title": { "type": "plain_text", "text": "Meeting Arranger" },
+///     "blocks": [],
+///     "hash": "156663117.cd33ad1f"
+///   },
+///   "is_cleared": true
+/// }))
+/// .unwrap();
+///
+/// match payload {
+///     Payload::ViewClosed(closed) => assert!(closed.is_cleared),
+///     _ => panic!("expected view_closed"),
+/// }
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct ViewClosed {
+    pub user: User,
+    pub api_app_id: String,
+    #[serde(default)]
+    pub token: String,
+    #[serde(default)]
+    pub team: Option<Team>,
+    #[serde(default)]
+    pub enterprise: Option<Enterprise>,
+    #[serde(default)]
+    pub is_enterprise_install: bool,
+    pub view: RenderedView,
+    /// Whether the whole stack of views was closed, rather than just this one
+    #[serde(default)]
+    pub is_cleared: bool,
+}
+
+/// [https://api.slack.com/surfaces/modals#modal_response_url](https://api.slack.com/surfaces/modals#modal_response_url)
+#[derive(Deserialize, Debug, Clone)]
+pub struct ResponseUrlInfo {
+    pub block_id: String,
+    pub action_id: String,
+    /// The channel that was picked. Messages sent to `response_url` go here.
+    pub channel_id: String,
+    pub response_url: String,
+}
@@ -6,3 +6,17 @@ extern crate typed_builder;
 extern crate impl_for;
 
 pub mod block_kit;
+pub mod dedup;
+pub mod events;
+pub mod incoming_webhook;
+pub mod interactivity;
+pub mod mrkdwn;
+pub mod oauth;
+pub mod openid;
+pub mod response_url;
+pub mod signature;
+pub mod slash_commands;
+pub mod socket_mode;
+pub mod web_api;
+
+mod util;
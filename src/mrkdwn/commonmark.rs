@@ -0,0 +1,665 @@
+//! Converts between CommonMark (with GitHub's tables, strikethrough and task
+//! lists) and Block Kit
+//!
+//! [`to_blocks`] maps:
+//!
+//! - headings to [`Header`]s, with any formatting dropped
+//! - thematic breaks to [`Divider`]s
+//! - images on a line of their own to [`Image`]s, and other images to links
+//! - everything else to mrkdwn [`Section`]s, split to fit Slack's 3000
+//!   character limit. Tables become preformatted text, since Slack can't show
+//!   them.
+//!
+//! [`from_blocks`] goes the other way, and [`from_mrkdwn`] converts mrkdwn
+//! text on its own.
+//!
+//! ```
+//! # use assert_json_diff::assert_json_eq;
+//! # use serde_json::json;
+//! # use slack_lib::mrkdwn::commonmark;
+//! let blocks = commonmark::to_blocks(
+//!     "# Release 1.2\n\
+//!      \n\
+//!      **Breaking:** `Client::new` now takes a [token](https://api.slack.com/authentication/token-types).\n\
+//!      \n\
+//!      ---\n\
+//!      \n\
+//!      ![Screenshot](https://example.com/screenshot.png)",
+//! );
+//!
+//! let expected = json!([
+//!   { "type": "header", "text": { "type": "plain_text", "text": "Release 1.2" } },
+//!   {
+//!     "type": "section",
+//!     "text": {
+//!       "type": "mrkdwn",
+//!       "text": "*Breaking:* `Client::new` now takes a <https://api.slack.com/authentication/token-types|token>."
+//!     }
+//!   },
+//!   { "type": "divider" },
+//!   { "type": "image", "image_url": "https://example.com/screenshot.png", "alt_text": "Screenshot" }
+//! ]);
+//!
+//! assert_json_eq!(expected, serde_json::to_value(&blocks).unwrap());
+//!
+//! assert_eq!(
+//!     commonmark::from_blocks(&blocks),
+//!     "# Release 1.2\n\
+//!      \n\
+//!      **Breaking:** `Client::new` now takes a [token](https://api.slack.com/authentication/token-types).\n\
+//!      \n\
+//!      ---\n\
+//!      \n\
+//!      ![Screenshot](https://example.com/screenshot.png)\n"
+//! );
+//! ```
+
+use super::escape;
+use super::parser::{self, Broadcast, Node};
+use crate::block_kit::blocks::{Divider, Header, Image, MessageBlock, Section};
+use crate::block_kit::composition::{Markdown, PlainText, Text};
+use crate::block_kit::elements::ContextElements;
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+
+/// The most text a [`Section`] can hold
+pub const SECTION_LIMIT: usize = 3000;
+
+/// The most text a [`Header`] can hold
+pub const HEADER_LIMIT: usize = 150;
+
+/// Convert CommonMark to blocks
+pub fn to_blocks(markdown: &str) -> Vec<MessageBlock> {
+    let options =
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    let mut converter = Converter::default();
+    for event in Parser::new_ext(markdown, options) {
+        converter.event(event);
+    }
+    converter.flush_section();
+    converter.blocks
+}
+
+#[derive(Default)]
+struct Converter {
+    blocks: Vec<MessageBlock>,
+    /// mrkdwn for the section being built. Quotes get a buffer of their own,
+    /// which is prefixed with `>`s and added to the one below when they end.
+    buffers: Vec<String>,
+    /// The next number for each list being built, or `None` for bullets
+    lists: Vec<Option<u64>>,
+    /// Set at the start of a list item, until something's written to it
+    item_started: bool,
+    /// Where each open link's label starts, and its URL
+    links: Vec<(usize, String)>,
+    /// Where the current paragraph starts
+    paragraph: Option<usize>,
+    /// Text for a heading, image alt text or table cell. Written unformatted.
+    plain: Option<String>,
+    /// An image that may turn out to be the only thing in its paragraph
+    image: Option<Image>,
+    /// The URL and title of the image whose alt text is being collected
+    image_source: Option<(String, String)>,
+    table: Option<Vec<Vec<String>>>,
+    in_code_block: bool,
+}
+
+impl Converter {
+    fn event(&mut self, event: Event<'_>) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) if self.in_code_block => self.write(&escape(&text)),
+            Event::Text(text) | Event::Html(text) | Event::InlineHtml(text) => self.text(&text),
+            Event::Code(code) => match &mut self.plain {
+                Some(plain) => plain.push_str(&code),
+                None => self.write(&format!("`{}`", escape(&code))),
+            },
+            Event::SoftBreak => self.text(" "),
+            Event::HardBreak => match &mut self.plain {
+                Some(plain) => plain.push(' '),
+                None => self.write("\n"),
+            },
+            Event::Rule if self.is_nested() => {
+                self.start_block();
+                self.write("\u{2014}\u{2014}\u{2014}");
+            }
+            Event::Rule => {
+                self.flush_section();
+                self.blocks.push(Divider::builder().build().into());
+            }
+            Event::TaskListMarker(checked) => {
+                self.write(if checked { "\u{2611} " } else { "\u{2610} " })
+            }
+            _ => {}
+        }
+    }
+
+    fn start(&mut self, tag: Tag<'_>) {
+        match tag {
+            Tag::Heading { .. } => {
+                if !self.is_nested() {
+                    self.flush_section();
+                }
+                self.plain = Some(String::new());
+            }
+            Tag::Paragraph => {
+                self.start_block();
+                self.paragraph = Some(self.buffer().len());
+            }
+            Tag::BlockQuote(_) => {
+                self.start_block();
+                self.buffers.push(String::new());
+            }
+            // Slack doesn't highlight code, so the language is dropped
+            Tag::CodeBlock(_) => {
+                self.start_block();
+                self.write("```\n");
+                self.in_code_block = true;
+            }
+            Tag::List(start) => {
+                self.start_block();
+                self.lists.push(start);
+            }
+            Tag::Item => {
+                let depth = self.lists.len().saturating_sub(1);
+                let marker = match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        *number += 1;
+                        format!("{}. ", *number - 1)
+                    }
+                    _ => String::from("\u{2022} "),
+                };
+                self.new_line();
+                self.write(&format!("{}{}", "    ".repeat(depth), marker));
+                self.item_started = true;
+            }
+            Tag::Table(_) => {
+                self.start_block();
+                self.table = Some(Vec::new());
+            }
+            Tag::TableHead | Tag::TableRow => {
+                if let Some(table) = &mut self.table {
+                    table.push(Vec::new());
+                }
+            }
+            Tag::TableCell => self.plain = Some(String::new()),
+            Tag::Emphasis => self.marker("_"),
+            Tag::Strong => self.marker("*"),
+            Tag::Strikethrough => self.marker("~"),
+            Tag::Link { dest_url, .. } if self.plain.is_none() => {
+                self.flush_image();
+                let start = self.buffer().len();
+                self.links.push((start, dest_url.into_string()));
+            }
+            Tag::Image {
+                dest_url, title, ..
+            } if self.plain.is_none() => {
+                self.flush_image();
+                self.image_source = Some((dest_url.into_string(), title.into_string()));
+                self.plain = Some(String::new());
+            }
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Heading(_) => {
+                let text = self.plain.take().unwrap_or_default();
+                let text = text.trim();
+                if self.is_nested() {
+                    self.start_block();
+                    self.write(&format!("*{}*", escape(text)));
+                } else if !text.is_empty() {
+                    let text = truncate(text, HEADER_LIMIT);
+                    self.blocks.push(
+                        Header::builder()
+                            .text(PlainText::from(text.as_str()))
+                            .build()
+                            .into(),
+                    );
+                }
+            }
+            TagEnd::Paragraph => {
+                let paragraph = self.paragraph.take();
+                if let Some(image) = self.image.take() {
+                    let alone =
+                        paragraph.is_some_and(|start| self.buffer()[start..].trim().is_empty());
+                    if alone && !self.is_nested() {
+                        self.buffer().truncate(paragraph.unwrap_or_default());
+                        self.flush_section();
+                        self.blocks.push(image.into());
+                    } else {
+                        self.image = Some(image);
+                        self.flush_image();
+                    }
+                }
+            }
+            TagEnd::BlockQuote(_) => {
+                let quote = self.buffers.pop().unwrap_or_default();
+                let quoted: Vec<String> = quote
+                    .trim_end()
+                    .lines()
+                    .map(|line| format!("> {}", line).trim_end().to_owned())
+                    .collect();
+                self.write(&quoted.join("\n"));
+            }
+            TagEnd::CodeBlock => {
+                if !self.buffer().ends_with('\n') {
+                    self.write("\n");
+                }
+                self.write("```");
+                self.in_code_block = false;
+            }
+            TagEnd::List(_) => {
+                self.lists.pop();
+            }
+            TagEnd::TableCell => {
+                let cell = self.plain.take().unwrap_or_default();
+                if let Some(row) = self.table.as_mut().and_then(|table| table.last_mut()) {
+                    row.push(cell.trim().to_owned());
+                }
+            }
+            TagEnd::Table => {
+                let table = self.table.take().unwrap_or_default();
+                self.write(&format!("```\n{}\n```", escape(&render_table(&table))));
+            }
+            TagEnd::Emphasis => self.marker("_"),
+            TagEnd::Strong => self.marker("*"),
+            TagEnd::Strikethrough => self.marker("~"),
+            TagEnd::Link if self.plain.is_none() => {
+                // A linked image, like a badge, is shown as its alt text
+                if let Some(image) = self.image.take() {
+                    self.buffer().push_str(&escape(&image.alt_text));
+                }
+                if let Some((start, url)) = self.links.pop() {
+                    let label = self.buffer().split_off(start);
+                    let url = escape(&url).replace('|', "%7C");
+                    if label.is_empty() || label == url {
+                        self.write(&format!("<{}>", url));
+                    } else {
+                        self.write(&format!("<{}|{}>", url, label));
+                    }
+                }
+            }
+            TagEnd::Image if self.image_source.is_some() => {
+                let alt = self.plain.take().unwrap_or_default();
+                let (url, title) = self.image_source.take().unwrap_or_default();
+                let alt = if alt.trim().is_empty() {
+                    url.clone()
+                } else {
+                    alt
+                };
+
+                let mut image = Image::builder().image_url(url).alt_text(alt).build();
+                if !title.is_empty() {
+                    image.title = Some(PlainText::from(title.as_str()));
+                }
+                self.image = Some(image);
+            }
+            _ => {}
+        }
+    }
+
+    fn buffer(&mut self) -> &mut String {
+        if self.buffers.is_empty() {
+            self.buffers.push(String::new());
+        }
+        let last = self.buffers.len() - 1;
+        &mut self.buffers[last]
+    }
+
+    fn is_nested(&self) -> bool {
+        self.buffers.len() > 1 || !self.lists.is_empty()
+    }
+
+    /// Text from the document, escaped unless it's plain
+    fn text(&mut self, text: &str) {
+        match &mut self.plain {
+            Some(plain) => plain.push_str(text),
+            None => self.write(&escape(text)),
+        }
+    }
+
+    fn marker(&mut self, marker: &str) {
+        if self.plain.is_none() {
+            self.write(marker);
+        }
+    }
+
+    /// Add mrkdwn to the section
+    fn write(&mut self, mrkdwn: &str) {
+        self.flush_image();
+        self.item_started = false;
+        self.buffer().push_str(mrkdwn);
+    }
+
+    /// An image turned out not to be alone in its paragraph, so show it as a
+    /// link
+    fn flush_image(&mut self) {
+        if let Some(image) = self.image.take() {
+            let url = escape(&image.image_url).replace('|', "%7C");
+            let link = format!("<{}|{}>", url, escape(&image.alt_text));
+            self.buffer().push_str(&link);
+        }
+    }
+
+    /// Separate a paragraph, list, quote or code block from what came before
+    fn start_block(&mut self) {
+        if self.item_started {
+            return;
+        }
+        let nested = self.is_nested();
+        let buffer = self.buffer();
+        if buffer.is_empty() {
+            return;
+        }
+        let separator = if nested { "\n" } else { "\n\n" };
+        while !buffer.ends_with(separator) {
+            buffer.push('\n');
+        }
+    }
+
+    fn new_line(&mut self) {
+        let buffer = self.buffer();
+        if !buffer.is_empty() && !buffer.ends_with('\n') {
+            buffer.push('\n');
+        }
+    }
+
+    fn flush_section(&mut self) {
+        self.flush_image();
+        let text = self.buffers.drain(..).collect::<String>();
+        for chunk in split(text.trim(), SECTION_LIMIT) {
+            self.blocks.push(
+                Section::builder()
+                    .text(Markdown::from(chunk.as_str()))
+                    .build()
+                    .into(),
+            );
+        }
+    }
+}
+
+/// Columns padded to line up, with a rule under the header
+fn render_table(rows: &[Vec<String>]) -> String {
+    let columns = rows.iter().map(Vec::len).max().unwrap_or_default();
+    let widths: Vec<usize> = (0..columns)
+        .map(|column| {
+            rows.iter()
+                .filter_map(|row| row.get(column))
+                .map(|cell| cell.chars().count())
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+
+    let mut lines: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            let cells: Vec<String> = widths
+                .iter()
+                .enumerate()
+                .map(|(column, width)| {
+                    let cell = row.get(column).map(String::as_str).unwrap_or_default();
+                    format!("{:width$}", cell, width = width)
+                })
+                .collect();
+            cells.join(" | ").trim_end().to_owned()
+        })
+        .collect();
+
+    if !lines.is_empty() {
+        let rule: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+        lines.insert(1, rule.join("-+-"));
+    }
+    lines.join("\n")
+}
+
+fn truncate(text: &str, limit: usize) -> String {
+    if text.chars().count() <= limit {
+        return text.to_owned();
+    }
+    let mut truncated: String = text.chars().take(limit - 1).collect();
+    truncated.push('\u{2026}');
+    truncated
+}
+
+/// Split mrkdwn into pieces of at most `limit` characters, preferring to
+/// break between paragraphs, then lines, then words. Code blocks that are
+/// split are closed and reopened.
+fn split(text: &str, limit: usize) -> Vec<String> {
+    const FENCE: &str = "\n```";
+
+    let mut chunks = Vec::new();
+    let mut rest = text.to_owned();
+    while rest.chars().count() > limit {
+        // Leave room to close a code block
+        let end = rest
+            .char_indices()
+            .nth(limit - FENCE.len())
+            .map(|(index, _)| index)
+            .unwrap_or(rest.len());
+        let window = &rest[..end];
+        // Breaking at the end of an opening fence line would just put the
+        // fence back in front of the remainder
+        let opening = if rest.starts_with("```") {
+            window.find('\n').unwrap_or(end)
+        } else {
+            0
+        };
+        let at = window
+            .rfind("\n\n")
+            .or_else(|| window.rfind('\n'))
+            .or_else(|| window.rfind(' '))
+            .filter(|at| *at > opening)
+            .unwrap_or(end);
+
+        let cut = |at: usize| {
+            let mut chunk = rest[..at].trim_end().to_owned();
+            let mut remainder = rest[at..].trim_start_matches(['\n', ' ']).to_owned();
+            if chunk.matches("```").count() % 2 == 1 {
+                chunk.push_str(FENCE);
+                remainder.insert_str(0, "```\n");
+            }
+            (chunk, remainder)
+        };
+        let (mut chunk, mut remainder) = cut(at);
+        // Every pass has to get through some of the text
+        if remainder.len() >= rest.len() {
+            (chunk, remainder) = cut(end);
+        }
+
+        chunks.push(chunk);
+        rest = remainder;
+    }
+    if !rest.is_empty() {
+        chunks.push(rest);
+    }
+    chunks
+}
+
+/// Convert blocks back to CommonMark. Interactive blocks are left out.
+pub fn from_blocks(blocks: &[MessageBlock]) -> String {
+    let mut paragraphs = Vec::new();
+    for block in blocks {
+        match block {
+            MessageBlock::Header(header) => {
+                paragraphs.push(format!("# {}", escape_commonmark(&header.text.text)));
+            }
+            MessageBlock::Divider(_) => paragraphs.push(String::from("---")),
+            MessageBlock::Image(image) => paragraphs.push(image_markdown(
+                &image.alt_text,
+                &image.image_url,
+                image.title.as_ref().map(|title| title.text.as_str()),
+            )),
+            MessageBlock::Section(section) => {
+                paragraphs.extend(section.text.iter().map(text_markdown));
+                for field in section.fields.iter().flatten() {
+                    paragraphs.push(text_markdown(field));
+                }
+            }
+            MessageBlock::Context(context) => {
+                let elements: Vec<String> = context
+                    .elements
+                    .iter()
+                    .map(|element| match element {
+                        ContextElements::Text(text) => text_markdown(text),
+                        ContextElements::Image(image) => {
+                            image_markdown(&image.alt_text, &image.image_url, None)
+                        }
+                    })
+                    .collect();
+                paragraphs.push(elements.join(" "));
+            }
+            MessageBlock::Actions(_) | MessageBlock::RichText(_) => {}
+        }
+    }
+
+    let mut markdown = paragraphs
+        .into_iter()
+        .filter(|paragraph| !paragraph.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    if !markdown.is_empty() {
+        markdown.push('\n');
+    }
+    markdown
+}
+
+fn text_markdown(text: &Text) -> String {
+    match text {
+        Text::Markdown(markdown) => from_mrkdwn(&markdown.text),
+        Text::PlainText(plain) => escape_commonmark(&plain.text),
+    }
+}
+
+fn image_markdown(alt: &str, url: &str, title: Option<&str>) -> String {
+    match title {
+        Some(title) => format!(
+            "![{}]({} \"{}\")",
+            escape_commonmark(alt),
+            url,
+            title.replace('"', "\\\"")
+        ),
+        None => format!("![{}]({})", escape_commonmark(alt), url),
+    }
+}
+
+/// Convert mrkdwn to CommonMark
+///
+/// ```
+/// # use slack_lib::mrkdwn::commonmark::from_mrkdwn;
+/// assert_eq!(
+///     from_mrkdwn("*Done* :tada: <https://example.com|details>\nthanks <@U111|alice>"),
+///     "**Done** :tada: [details](https://example.com)\\\nthanks @alice"
+/// );
+/// ```
+pub fn from_mrkdwn(mrkdwn: &str) -> String {
+    let mut markdown = String::new();
+    write_nodes(&parser::parse(mrkdwn), &mut markdown);
+    markdown.trim_end().to_owned()
+}
+
+fn write_nodes(nodes: &[Node], out: &mut String) {
+    let mut index = 0;
+    while index < nodes.len() {
+        match &nodes[index] {
+            Node::LineBreak => {
+                let mut breaks = 1;
+                while let Some(Node::LineBreak) = nodes.get(index + breaks) {
+                    breaks += 1;
+                }
+                let previous = index.checked_sub(1).and_then(|index| nodes.get(index));
+                let next = nodes.get(index + breaks);
+                let block_edge = [previous, next]
+                    .iter()
+                    .any(|node| matches!(node, Some(Node::Quote(_)) | Some(Node::CodeBlock(_))));
+
+                if breaks > 1 || block_edge {
+                    out.push_str("\n\n");
+                } else {
+                    out.push_str("\\\n");
+                }
+                index += breaks;
+                continue;
+            }
+            Node::Text(text) => out.push_str(&escape_commonmark(text)),
+            Node::Bold(children) => wrap("**", children, out),
+            Node::Italic(children) => wrap("_", children, out),
+            Node::Strike(children) => wrap("~~", children, out),
+            Node::Code(code) if code.contains('`') => {
+                out.push_str("`` ");
+                out.push_str(code);
+                out.push_str(" ``");
+            }
+            Node::Code(code) => {
+                out.push('`');
+                out.push_str(code);
+                out.push('`');
+            }
+            Node::CodeBlock(code) => {
+                if !out.is_empty() && !out.ends_with("\n\n") {
+                    out.push_str("\n\n");
+                }
+                out.push_str("```\n");
+                out.push_str(code);
+                out.push_str("\n```");
+            }
+            Node::Quote(children) => {
+                let mut quote = String::new();
+                write_nodes(children, &mut quote);
+                let quoted: Vec<String> = quote
+                    .lines()
+                    .map(|line| format!("> {}", line).trim_end().to_owned())
+                    .collect();
+                out.push_str(&quoted.join("\n"));
+            }
+            Node::Link { url, label } => match label {
+                Some(label) if label != url => {
+                    out.push_str(&format!("[{}]({})", escape_commonmark(label), url));
+                }
+                _ => out.push_str(&format!("<{}>", url)),
+            },
+            Node::User { id, label } | Node::Usergroup { id, label } => {
+                let name = label.as_deref().unwrap_or(id);
+                out.push('@');
+                out.push_str(&escape_commonmark(name.trim_start_matches('@')));
+            }
+            Node::Channel { id, label } => {
+                out.push('#');
+                out.push_str(&escape_commonmark(label.as_deref().unwrap_or(id)));
+            }
+            Node::Broadcast(broadcast) => out.push_str(match broadcast {
+                Broadcast::Here => "@here",
+                Broadcast::Channel => "@channel",
+                Broadcast::Everyone => "@everyone",
+            }),
+            Node::Date { fallback, .. } => out.push_str(&escape_commonmark(fallback)),
+            Node::Emoji(name) => {
+                out.push(':');
+                out.push_str(name);
+                out.push(':');
+            }
+        }
+        index += 1;
+    }
+}
+
+fn wrap(marker: &str, children: &[Node], out: &mut String) {
+    out.push_str(marker);
+    write_nodes(children, out);
+    out.push_str(marker);
+}
+
+/// Backslash-escape anything CommonMark would treat as formatting
+fn escape_commonmark(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if "\\`*_[]<>~#|".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
@@ -0,0 +1,250 @@
+//! [https://api.slack.com/reference/surfaces/formatting](https://api.slack.com/reference/surfaces/formatting)
+//!
+//! Slack's mrkdwn treats `<`, `>` and `&` as control characters, so text from
+//! users has to be escaped before it goes into a [`Markdown`], or it may be
+//! read as a mention or link. [`MrkdwnBuilder`] escapes everything passed to
+//! it, and formats mentions, links and dates. Going the other way,
+//! [`parser::parse`] turns mrkdwn into a tree of nodes, and [`commonmark`]
+//! converts between mrkdwn and standard Markdown.
+//!
+//! ```
+//! # use slack_lib::mrkdwn::*;
+//! let name = "<!channel> & friends";
+//!
+//! let text = MrkdwnBuilder::new()
+//!     .user("U024BE7LH")
+//!     .text(" renamed the project to ")
+//!     .bold(name)
+//!     .build();
+//!
+//! assert_eq!(
+//!     text.text,
+//!     "<@U024BE7LH> renamed the project to *&lt;!channel&gt; &amp; friends*"
+//! );
+//! ```
+
+pub mod commonmark;
+pub mod parser;
+
+use crate::block_kit::composition::Markdown;
+
+/// Escape `&`, `<` and `>`, so `text` shows up as written
+///
+/// ```
+/// # use slack_lib::mrkdwn::escape;
+/// assert_eq!(escape("1 < 2 && 3 > 2"), "1 &lt; 2 &amp;&amp; 3 &gt; 2");
+/// ```
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Builds up mrkdwn text piece by piece. Any text passed in is escaped.
+///
+/// Styles can't be nested by passing one builder's output to another, since
+/// it'd be escaped. Use [`raw`](Self::raw) for text that's already mrkdwn.
+///
+/// ```
+/// # use slack_lib::mrkdwn::*;
+/// let text = MrkdwnBuilder::new()
+///     .at_here()
+///     .text(" deploy of ")
+///     .code("main")
+///     .text(" finished ")
+///     .emoji("tada")
+///     .newline()
+///     .link("https://example.com/deploys?id=1&env=prod", "View logs")
+///     .text(" \u{2022} ")
+///     .date(1392734382, "{date_short_pretty} at {time}", "Feb 18, 2014")
+///     .quote("Shipped by <script>")
+///     .build();
+///
+/// assert_eq!(
+///     text.text,
+///     "<!here> deploy of `main` finished :tada:\n\
+///      <https://example.com/deploys?id=1&amp;env=prod|View logs> \u{2022} \
+///      <!date^1392734382^{date_short_pretty} at {time}|Feb 18, 2014>\n\
+///      > Shipped by &lt;script&gt;\n"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MrkdwnBuilder {
+    text: String,
+}
+
+impl MrkdwnBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Plain text
+    pub fn text(mut self, text: &str) -> Self {
+        self.text.push_str(&escape(text));
+        self
+    }
+
+    /// Text that's already mrkdwn, added as-is
+    pub fn raw(mut self, mrkdwn: &str) -> Self {
+        self.text.push_str(mrkdwn);
+        self
+    }
+
+    pub fn bold(self, text: &str) -> Self {
+        self.wrapped('*', text)
+    }
+
+    pub fn italic(self, text: &str) -> Self {
+        self.wrapped('_', text)
+    }
+
+    pub fn strike(self, text: &str) -> Self {
+        self.wrapped('~', text)
+    }
+
+    /// Inline code. Slack has no way to escape a backtick inside code, so
+    /// don't include one.
+    pub fn code(self, text: &str) -> Self {
+        self.wrapped('`', text)
+    }
+
+    /// A preformatted block, on its own lines
+    pub fn code_block(mut self, text: &str) -> Self {
+        self.start_line();
+        self.text.push_str("```\n");
+        self.text.push_str(&escape(text));
+        self.text.push_str("\n```\n");
+        self
+    }
+
+    /// A block quote, on its own lines. Every line of `text` is quoted.
+    pub fn quote(mut self, text: &str) -> Self {
+        self.start_line();
+        for line in text.lines() {
+            // Has to be a literal `>`, not an escaped one
+            self.text.push_str("> ");
+            self.text.push_str(&escape(line));
+            self.text.push('\n');
+        }
+        self
+    }
+
+    pub fn newline(mut self) -> Self {
+        self.text.push('\n');
+        self
+    }
+
+    /// A link showing `label` rather than the URL
+    pub fn link(mut self, url: &str, label: &str) -> Self {
+        // `|` separates the URL from the label
+        let url = escape(url).replace('|', "%7C");
+        self.text.push('<');
+        self.text.push_str(&url);
+        if !label.is_empty() {
+            self.text.push('|');
+            self.text.push_str(&escape(label));
+        }
+        self.text.push('>');
+        self
+    }
+
+    /// Mention a user, by ID, e.g. `U024BE7LH`
+    pub fn user(self, id: &str) -> Self {
+        self.mention('@', id)
+    }
+
+    /// Link to a channel, by ID, e.g. `C024BE7LR`
+    pub fn channel(self, id: &str) -> Self {
+        self.mention('#', id)
+    }
+
+    /// Mention a user group, by ID, e.g. `SAZ94GDB8`
+    pub fn usergroup(mut self, id: &str) -> Self {
+        self.text.push_str("<!subteam^");
+        self.text.push_str(&escape(id));
+        self.text.push('>');
+        self
+    }
+
+    /// `@here`: everyone active in the channel
+    pub fn at_here(self) -> Self {
+        self.raw("<!here>")
+    }
+
+    /// `@channel`: everyone in the channel
+    pub fn at_channel(self) -> Self {
+        self.raw("<!channel>")
+    }
+
+    /// `@everyone`: everyone in the workspace, in `#general`
+    pub fn at_everyone(self) -> Self {
+        self.raw("<!everyone>")
+    }
+
+    /// A date shown in the reader's timezone. `ts` is a Unix timestamp, and
+    /// `format` uses tokens like `{date_short}` or `{time}`. `fallback` is
+    /// shown by clients that can't format dates.
+    ///
+    /// [https://api.slack.com/reference/surfaces/formatting#date-formatting](https://api.slack.com/reference/surfaces/formatting#date-formatting)
+    pub fn date(mut self, ts: i64, format: &str, fallback: &str) -> Self {
+        self.text.push_str("<!date^");
+        self.text.push_str(&ts.to_string());
+        self.text.push('^');
+        self.text.push_str(&escape(format).replace('|', ""));
+        self.text.push('|');
+        self.text.push_str(&escape(fallback));
+        self.text.push('>');
+        self
+    }
+
+    /// An emoji, by name, with or without the colons
+    pub fn emoji(mut self, name: &str) -> Self {
+        self.text.push(':');
+        self.text.push_str(&escape(name.trim_matches(':')));
+        self.text.push(':');
+        self
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn build(self) -> Markdown {
+        Markdown::builder().text(self.text).build()
+    }
+
+    fn wrapped(mut self, marker: char, text: &str) -> Self {
+        self.text.push(marker);
+        self.text.push_str(&escape(text));
+        self.text.push(marker);
+        self
+    }
+
+    fn mention(mut self, sigil: char, id: &str) -> Self {
+        self.text.push('<');
+        self.text.push(sigil);
+        self.text.push_str(&escape(id));
+        self.text.push('>');
+        self
+    }
+
+    /// Make sure what comes next starts on a new line
+    fn start_line(&mut self) {
+        if !self.text.is_empty() && !self.text.ends_with('\n') {
+            self.text.push('\n');
+        }
+    }
+}
+
+impl From<MrkdwnBuilder> for Markdown {
+    fn from(builder: MrkdwnBuilder) -> Self {
+        builder.build()
+    }
+}
@@ -0,0 +1,437 @@
+//! Parses mrkdwn into a tree of [`Node`]s
+//!
+//! This follows what Slack's clients do, rather than any spec (there isn't
+//! one):
+//!
+//! - `*`, `_` and `~` only start a style at the start of a word, and only end
+//!   one at the end of a word, so `snake_case_name` isn't italic
+//! - Styles don't carry across lines, and an unclosed marker is just text
+//! - Nothing is formatted inside code, but entities (`&amp;`, `&lt;` and
+//!   `&gt;`) are still decoded
+//! - Quotes start with `>`, or `&gt;` as it appears in the `text` of
+//!   messages sent by users
+//!
+//! ```
+//! # use slack_lib::mrkdwn::parser::*;
+//! let nodes = parse("*Deploy* of `main` by <@U024BE7LH|alice> &amp; <!here>");
+//!
+//! assert_eq!(
+//!     nodes,
+//!     vec![
+//!         Node::Bold(vec![Node::Text(String::from("Deploy"))]),
+//!         Node::Text(String::from(" of ")),
+//!         Node::Code(String::from("main")),
+//!         Node::Text(String::from(" by ")),
+//!         Node::User {
+//!             id: String::from("U024BE7LH"),
+//!             label: Some(String::from("alice")),
+//!         },
+//!         Node::Text(String::from(" & ")),
+//!         Node::Broadcast(Broadcast::Here),
+//!     ]
+//! );
+//! ```
+
+/// A piece of parsed mrkdwn. Text is unescaped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Node {
+    Text(String),
+    LineBreak,
+    Bold(Vec<Node>),
+    Italic(Vec<Node>),
+    Strike(Vec<Node>),
+    Code(String),
+    /// A ```` ``` ```` block
+    CodeBlock(String),
+    /// One or more quoted lines, separated by [`Node::LineBreak`]s
+    Quote(Vec<Node>),
+    Link {
+        url: String,
+        label: Option<String>,
+    },
+    User {
+        id: String,
+        label: Option<String>,
+    },
+    Channel {
+        id: String,
+        label: Option<String>,
+    },
+    Usergroup {
+        id: String,
+        label: Option<String>,
+    },
+    Broadcast(Broadcast),
+    /// `<!date^ts^format^link|fallback>`
+    Date {
+        ts: i64,
+        format: String,
+        link: Option<String>,
+        fallback: String,
+    },
+    /// The name, without colons. Skin tones are separate emoji, e.g.
+    /// `skin-tone-2`.
+    Emoji(String),
+}
+
+impl Node {
+    /// The nodes inside a style or quote
+    pub fn children(&self) -> &[Node] {
+        match self {
+            Node::Bold(children)
+            | Node::Italic(children)
+            | Node::Strike(children)
+            | Node::Quote(children) => children,
+            _ => &[],
+        }
+    }
+}
+
+/// `@here`, `@channel` and `@everyone`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Broadcast {
+    Here,
+    Channel,
+    Everyone,
+}
+
+/// Call `f` on every node, parents before their children
+///
+/// ```
+/// # use slack_lib::mrkdwn::parser::*;
+/// let nodes = parse("> ping <@U111> and *<@U222>*\nthanks <@U111>");
+///
+/// let mut users = vec![];
+/// walk(&nodes, &mut |node| {
+///     if let Node::User { id, .. } = node {
+///         users.push(id.as_str());
+///     }
+/// });
+///
+/// assert_eq!(users, vec!["U111", "U222", "U111"]);
+/// ```
+pub fn walk<'a>(nodes: &'a [Node], f: &mut impl FnMut(&'a Node)) {
+    for node in nodes {
+        f(node);
+        walk(node.children(), f);
+    }
+}
+
+/// Parse mrkdwn, e.g. the `text` of a message or a
+/// [`Markdown`](crate::block_kit::composition::Markdown)
+///
+/// ```
+/// # use slack_lib::mrkdwn::parser::*;
+/// let nodes = parse("&gt; _quoted_\n```\nlet x = a &lt; b;\n```");
+///
+/// assert_eq!(
+///     nodes,
+///     vec![
+///         Node::Quote(vec![Node::Italic(vec![Node::Text(String::from("quoted"))])]),
+///         Node::LineBreak,
+///         Node::CodeBlock(String::from("let x = a < b;")),
+///     ]
+/// );
+/// ```
+pub fn parse(text: &str) -> Vec<Node> {
+    let mut nodes = Vec::new();
+    let mut rest = text;
+
+    while let Some(open) = rest.find("```") {
+        let after = &rest[open + 3..];
+        let close = match after.find("```") {
+            Some(close) => close,
+            None => break,
+        };
+
+        parse_lines(&rest[..open], &mut nodes);
+        let code = after[..close].strip_prefix('\n').unwrap_or(&after[..close]);
+        let code = code.strip_suffix('\n').unwrap_or(code);
+        nodes.push(Node::CodeBlock(decode(code)));
+        rest = &after[close + 3..];
+    }
+    parse_lines(rest, &mut nodes);
+
+    nodes
+}
+
+/// Text outside code blocks. Every newline becomes a [`Node::LineBreak`], and
+/// runs of quoted lines are collected into a [`Node::Quote`].
+fn parse_lines(text: &str, nodes: &mut Vec<Node>) {
+    if text.is_empty() {
+        return;
+    }
+
+    let mut quote: Option<Vec<Node>> = None;
+    for (index, line) in text.split('\n').enumerate() {
+        let quoted = line
+            .strip_prefix("&gt;")
+            .or_else(|| line.strip_prefix('>'))
+            .map(|line| line.strip_prefix(' ').unwrap_or(line));
+
+        match (quoted, &mut quote) {
+            (Some(line), Some(quote)) => {
+                quote.push(Node::LineBreak);
+                quote.extend(parse_inline(line));
+                continue;
+            }
+            (Some(line), None) => {
+                if index > 0 {
+                    nodes.push(Node::LineBreak);
+                }
+                quote = Some(parse_inline(line));
+                continue;
+            }
+            (None, _) => {}
+        }
+
+        if let Some(quote) = quote.take() {
+            nodes.push(Node::Quote(quote));
+        }
+        if index > 0 {
+            nodes.push(Node::LineBreak);
+        }
+        nodes.extend(parse_inline(line));
+    }
+
+    if let Some(quote) = quote {
+        nodes.push(Node::Quote(quote));
+    }
+}
+
+/// A single line
+fn parse_inline(text: &str) -> Vec<Node> {
+    let mut line = Line::new(text);
+    let mut nodes = Vec::new();
+    let mut text_start = 0;
+    let mut i = 0;
+
+    while i < text.len() {
+        let c = text[i..].chars().next().unwrap_or_default();
+        let parsed = match c {
+            '`' => parse_code(&line, i),
+            '<' => parse_angle(&line, i),
+            ':' => parse_emoji(&line, i),
+            '*' | '_' | '~' => parse_style(&mut line, i, c),
+            _ => None,
+        };
+
+        match parsed {
+            Some((node, end)) => {
+                push_text(&mut nodes, &text[text_start..i]);
+                nodes.push(node);
+                i = end;
+                text_start = end;
+            }
+            None => i += c.len_utf8(),
+        }
+    }
+    push_text(&mut nodes, &text[text_start..]);
+
+    nodes
+}
+
+/// A line being parsed, with lookups that save rescanning the rest of it
+/// from every unclosed marker, which would make parsing quadratic
+struct Line<'a> {
+    text: &'a str,
+    /// The index of the next `` ` ``, and of the next `>`, from each index on
+    next_tick: Vec<Option<usize>>,
+    next_angle: Vec<Option<usize>>,
+    /// Where the search for a closing `*`, `_` or `~` ends, by the index it
+    /// passed through, once known
+    closers: [Vec<Option<Option<usize>>>; 3],
+}
+
+impl<'a> Line<'a> {
+    fn new(text: &'a str) -> Self {
+        let next = |target| {
+            let mut next = vec![None; text.len() + 1];
+            for (i, &byte) in text.as_bytes().iter().enumerate().rev() {
+                next[i] = if byte == target { Some(i) } else { next[i + 1] };
+            }
+            next
+        };
+
+        Line {
+            text,
+            next_tick: next(b'`'),
+            next_angle: next(b'>'),
+            closers: [(); 3].map(|_| vec![None; text.len()]),
+        }
+    }
+
+    /// The first `marker` from `from` on that ends a word, skipping over
+    /// code and anything in `<>`
+    fn closer(&mut self, marker: char, from: usize) -> Option<usize> {
+        let line = self.text;
+        let closers = match marker {
+            '*' => &mut self.closers[0],
+            '_' => &mut self.closers[1],
+            _ => &mut self.closers[2],
+        };
+        let mut visited = Vec::new();
+        let mut i = from;
+
+        let found = loop {
+            if i >= line.len() {
+                break None;
+            }
+            if let Some(found) = closers[i] {
+                break found;
+            }
+            visited.push(i);
+
+            let c = line[i..].chars().next().unwrap_or_default();
+            match c {
+                '`' | '<' => {
+                    let next = if c == '`' {
+                        &self.next_tick
+                    } else {
+                        &self.next_angle
+                    };
+                    if let Some(close) = next[i + 1] {
+                        i = close + 1;
+                        continue;
+                    }
+                }
+                c if c == marker => {
+                    let before = line[..i].chars().next_back();
+                    let after = line[i + 1..].chars().next();
+                    if !before.is_some_and(char::is_whitespace) && !after.is_some_and(is_word_char)
+                    {
+                        break Some(i);
+                    }
+                }
+                _ => {}
+            }
+            i += c.len_utf8();
+        };
+
+        // Any search passing through these ends the same way
+        for i in visited {
+            closers[i] = Some(found);
+        }
+        found
+    }
+}
+
+fn push_text(nodes: &mut Vec<Node>, text: &str) {
+    if !text.is_empty() {
+        nodes.push(Node::Text(decode(text)));
+    }
+}
+
+/// Each of these returns the node starting at `start`, and the index just
+/// after it
+fn parse_code(line: &Line, start: usize) -> Option<(Node, usize)> {
+    let close = line.next_tick[start + 1]?;
+    if close == start + 1 {
+        return None;
+    }
+    Some((Node::Code(decode(&line.text[start + 1..close])), close + 1))
+}
+
+fn parse_angle(line: &Line, start: usize) -> Option<(Node, usize)> {
+    let close = line.next_angle[start + 1]?;
+    let inner = &line.text[start + 1..close];
+    let (target, label) = match inner.split_once('|') {
+        Some((target, label)) => (target, Some(decode(label))),
+        None => (inner, None),
+    };
+
+    let node = if let Some(id) = target.strip_prefix('@') {
+        Node::User {
+            id: id.to_owned(),
+            label,
+        }
+    } else if let Some(id) = target.strip_prefix('#') {
+        Node::Channel {
+            id: id.to_owned(),
+            label,
+        }
+    } else if let Some(id) = target.strip_prefix("!subteam^") {
+        Node::Usergroup {
+            id: id.to_owned(),
+            label,
+        }
+    } else if let Some(date) = target.strip_prefix("!date^") {
+        let mut parts = date.splitn(3, '^');
+        let ts = parts.next()?.parse().ok()?;
+        Node::Date {
+            ts,
+            format: decode(parts.next()?),
+            link: parts.next().map(decode),
+            fallback: label.unwrap_or_default(),
+        }
+    } else if let Some(command) = target.strip_prefix('!') {
+        match command {
+            "here" => Node::Broadcast(Broadcast::Here),
+            "channel" => Node::Broadcast(Broadcast::Channel),
+            "everyone" => Node::Broadcast(Broadcast::Everyone),
+            // Some other (probably retired) command, shown as its label
+            command => Node::Text(label.unwrap_or_else(|| decode(command))),
+        }
+    } else {
+        Node::Link {
+            url: decode(target),
+            label,
+        }
+    };
+
+    Some((node, close + 1))
+}
+
+fn parse_emoji(line: &Line, start: usize) -> Option<(Node, usize)> {
+    let line = line.text;
+    // `10:30:00` isn't an emoji
+    if line[..start]
+        .chars()
+        .next_back()
+        .is_some_and(char::is_alphanumeric)
+    {
+        return None;
+    }
+
+    let name_len =
+        line[start + 1..].find(|c: char| !(c.is_ascii_alphanumeric() || "_+-'".contains(c)))?;
+    if name_len == 0 || !line[start + 1 + name_len..].starts_with(':') {
+        return None;
+    }
+
+    let name = &line[start + 1..start + 1 + name_len];
+    Some((Node::Emoji(name.to_owned()), start + name_len + 2))
+}
+
+fn parse_style(line: &mut Line, start: usize, marker: char) -> Option<(Node, usize)> {
+    let before = line.text[..start].chars().next_back();
+    let after = line.text[start + 1..].chars().next();
+    if before.is_some_and(is_word_char) || after.is_none_or(char::is_whitespace) {
+        return None;
+    }
+
+    // A marker straight after this one can't close it
+    let from = if after == Some(marker) {
+        start + 2
+    } else {
+        start + 1
+    };
+    let close = line.closer(marker, from)?;
+    let children = parse_inline(&line.text[start + 1..close]);
+    let node = match marker {
+        '*' => Node::Bold(children),
+        '_' => Node::Italic(children),
+        _ => Node::Strike(children),
+    };
+    Some((node, close + 1))
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric()
+}
+
+fn decode(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
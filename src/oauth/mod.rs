@@ -0,0 +1,581 @@
+//! [https://api.slack.com/authentication/oauth-v2](https://api.slack.com/authentication/oauth-v2)
+//!
+//! Installing an app into a workspace goes:
+//!
+//! 1. Send the user to [`InstallProvider::authorize_url`], with a `state`
+//!    from [`InstallProvider::generate_state`]
+//! 2. Slack sends them back to the redirect URI with a `code` and the same
+//!    `state`
+//! 3. Pass the query string to [`InstallProvider::handle_redirect`], which
+//!    checks the `state` and exchanges the `code` for an [`Installation`]
+//!
+//! The `state` is signed and carries its own expiry, so nothing needs to be
+//! stored between steps one and three.
+//!
+//! ```no_run
+//! # use slack_lib::oauth::*;
+//! # async fn run(query: &str) -> Result<(), Error> {
+//! let provider = InstallProvider::builder()
+//!     .client_id("4b39e9-752c4")
+//!     .client_secret("33fea0113f5b1")
+//!     .state_secret("a long random string")
+//!     .scopes(vec![String::from("chat:write"), String::from("commands")])
+//!     .build();
+//!
+//! // Redirect the user here
+//! let url = provider.authorize_url(&provider.generate_state());
+//!
+//! // ...and when they come back
+//! let installation = provider.handle_redirect(query).await?;
+//! println!("installed into {:?}", installation.team_id());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! With token rotation turned on, bot and user tokens expire after 12 hours.
+//! Keep installations in an [`InstallationStore`] and look them up with
+//! [`InstallProvider::find_installation`], which refreshes tokens before they
+//! expire.
+
+pub mod store;
+
+use self::store::InstallationStore;
+use crate::util::{random_hex, unix_time};
+use crate::web_api::oauth::{
+    Grant, IncomingWebhookInfo, OauthTeam, OauthV2Access, OauthV2AccessResponse,
+};
+use crate::web_api::{self, Client};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+pub const AUTHORIZE_URL: &str = "https://slack.com/oauth/v2/authorize";
+
+/// Everything an app gets from being installed into a workspace (or, for
+/// org-wide installs, an Enterprise Grid organization)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Installation {
+    pub app_id: String,
+    /// Unset for org-wide installs
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<OauthTeam>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enterprise: Option<OauthTeam>,
+    #[serde(default)]
+    pub is_enterprise_install: bool,
+    /// Unset if no bot scopes were requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bot: Option<InstalledBot>,
+    pub user: InstalledUser,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub incoming_webhook: Option<IncomingWebhookInfo>,
+    /// Unix timestamp
+    pub installed_at: i64,
+}
+
+impl Installation {
+    pub fn team_id(&self) -> Option<&str> {
+        self.team.as_ref().map(|team| team.id.as_str())
+    }
+
+    pub fn enterprise_id(&self) -> Option<&str> {
+        self.enterprise
+            .as_ref()
+            .map(|enterprise| enterprise.id.as_str())
+    }
+
+    fn from_response(response: OauthV2AccessResponse, now: i64) -> Self {
+        let bot = match (response.token_type.as_deref(), response.access_token) {
+            (Some("bot"), Some(token)) => Some(InstalledBot {
+                token,
+                user_id: response.bot_user_id.unwrap_or_default(),
+                scopes: split_scopes(response.scope.as_deref()),
+                refresh_token: response.refresh_token,
+                expires_at: response.expires_in.map(|expires_in| now + expires_in),
+            }),
+            _ => None,
+        };
+
+        let user = match response.authed_user {
+            Some(user) => InstalledUser {
+                id: user.id,
+                token: user.access_token,
+                scopes: split_scopes(user.scope.as_deref()),
+                refresh_token: user.refresh_token,
+                expires_at: user.expires_in.map(|expires_in| now + expires_in),
+            },
+            None => InstalledUser::default(),
+        };
+
+        Installation {
+            app_id: response.app_id,
+            team: response.team,
+            enterprise: response.enterprise,
+            is_enterprise_install: response.is_enterprise_install,
+            bot,
+            user,
+            incoming_webhook: response.incoming_webhook,
+            installed_at: now,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct InstalledBot {
+    /// `xoxb-...`, or `xoxe.xoxb-...` with token rotation on
+    pub token: String,
+    pub user_id: String,
+    #[serde(default)]
+    pub scopes: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+    /// Unix timestamp. Only set with token rotation on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<i64>,
+}
+
+/// The user who installed the app
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct InstalledUser {
+    pub id: String,
+    /// Only set if user scopes were requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    #[serde(default)]
+    pub scopes: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<i64>,
+}
+
+fn split_scopes(scopes: Option<&str>) -> Vec<String> {
+    scopes
+        .unwrap_or_default()
+        .split(',')
+        .filter(|scope| !scope.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+#[derive(Debug)]
+pub enum Error {
+    /// The `state` wasn't one we generated, or has expired
+    State(StateError),
+    /// The user cancelled the install. Holds the `error` Slack redirected
+    /// with, usually `access_denied`.
+    Denied(String),
+    /// The redirect had no `code`
+    MissingCode,
+    /// `oauth.v2.access` failed
+    WebApi(web_api::Error),
+    /// The [`InstallationStore`] failed
+    Store(store::Error),
+    /// Refreshing a token succeeded, but Slack didn't send a new one
+    MissingToken,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::State(err) => write!(f, "invalid state: {}", err),
+            Error::Denied(error) => write!(f, "install was not approved: {}", error),
+            Error::MissingCode => f.write_str("redirect has no code"),
+            Error::WebApi(err) => write!(f, "oauth.v2.access failed: {}", err),
+            Error::Store(err) => write!(f, "{}", err),
+            Error::MissingToken => f.write_str("token refresh returned no access token"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::State(err) => Some(err),
+            Error::WebApi(err) => Some(err),
+            Error::Store(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<StateError> for Error {
+    fn from(err: StateError) -> Self {
+        Error::State(err)
+    }
+}
+
+impl From<web_api::Error> for Error {
+    fn from(err: web_api::Error) -> Self {
+        Error::WebApi(err)
+    }
+}
+
+impl From<store::Error> for Error {
+    fn from(err: store::Error) -> Self {
+        Error::Store(err)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StateError {
+    /// Not in the format [`InstallProvider::generate_state`] produces
+    Malformed,
+    Expired,
+    /// Signed with a different secret, or tampered with
+    Mismatch,
+}
+
+impl fmt::Display for StateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StateError::Malformed => f.write_str("state is malformed"),
+            StateError::Expired => f.write_str("state has expired"),
+            StateError::Mismatch => f.write_str("state signature does not match"),
+        }
+    }
+}
+
+impl std::error::Error for StateError {}
+
+/// Create a builder with [`InstallProvider::builder()`]
+///
+/// Available fields:
+///
+/// - `.client_id(`[`Into`]`<`[`String`]`>)` - required
+/// - `.client_secret(`[`Into`]`<`[`String`]`>)` - required
+/// - `.state_secret(`[`Into`]`<`[`String`]`>)` - required, used to sign
+///   `state`
+/// - `.scopes(`[`Vec`]`<`[`String`]`>)` - optional, bot scopes
+/// - `.user_scopes(`[`Vec`]`<`[`String`]`>)` - optional
+/// - `.redirect_uri(`[`Into`]`<`[`String`]`>)` - optional, defaults to the
+///   first one configured for the app
+/// - `.state_ttl(`[`Duration`]`)` - optional, defaults to 10 minutes
+/// - `.authorize_url(`[`Url`](reqwest::Url)`)` - optional
+/// - `.refresh_before(`[`Duration`]`)` - optional, how long before they
+///   expire to refresh rotating tokens. Defaults to 2 hours.
+/// - `.client(`[`Client`]`)` - optional, used to call `oauth.v2.access`
+///
+/// Finalise the builder with `.build()` to retrieve an [`InstallProvider`]
+///
+/// ```
+/// # use slack_lib::oauth::*;
+/// let provider = InstallProvider::builder()
+///     .client_id("4b39e9-752c4")
+///     .client_secret("33fea0113f5b1")
+///     .state_secret("a long random string")
+///     .scopes(vec![String::from("chat:write"), String::from("commands")])
+///     .user_scopes(vec![String::from("search:read")])
+///     .redirect_uri("https://example.com/slack/oauth_redirect")
+///     .build();
+///
+/// let state = provider.generate_state();
+/// assert_eq!(provider.verify_state(&state), Ok(()));
+///
+/// let url = provider.authorize_url(&state);
+/// assert!(url.starts_with("https://slack.com/oauth/v2/authorize?client_id=4b39e9-752c4"));
+/// assert!(url.contains("&scope=chat%3Awrite%2Ccommands&user_scope=search%3Aread"));
+///
+/// let mut tampered = state.clone();
+/// tampered.replace_range(..1, if state.starts_with('0') { "1" } else { "0" });
+/// assert_eq!(provider.verify_state(&tampered), Err(StateError::Mismatch));
+/// ```
+#[derive(TypedBuilder, Clone)]
+pub struct InstallProvider {
+    #[builder(setter(into))]
+    client_id: String,
+
+    #[builder(setter(into))]
+    client_secret: String,
+
+    #[builder(setter(into))]
+    state_secret: String,
+
+    #[builder(default)]
+    scopes: Vec<String>,
+
+    #[builder(default)]
+    user_scopes: Vec<String>,
+
+    #[builder(setter(into, strip_option), default)]
+    redirect_uri: Option<String>,
+
+    #[builder(default = Duration::from_secs(10 * 60))]
+    state_ttl: Duration,
+
+    #[builder(default = reqwest::Url::parse(AUTHORIZE_URL).expect("AUTHORIZE_URL is valid"))]
+    authorize_url: reqwest::Url,
+
+    #[builder(default = Duration::from_secs(2 * 60 * 60))]
+    refresh_before: Duration,
+
+    #[builder(default = Client::new(""))]
+    client: Client,
+
+    /// One per installation being refreshed, shared between clones
+    #[builder(setter(skip), default)]
+    refresh_locks: Arc<Mutex<HashMap<InstallationKey, Arc<tokio::sync::Mutex<()>>>>>,
+}
+
+type InstallationKey = (Option<String>, Option<String>);
+
+impl InstallProvider {
+    /// A new `state`, good for `state_ttl`
+    pub fn generate_state(&self) -> String {
+        let expires_at = unix_time(SystemTime::now()) + self.state_ttl.as_secs() as i64;
+        let unsigned = format!("{}.{}", random_hex(16), expires_at);
+        let signature = hex::encode(self.state_mac(&unsigned).finalize().into_bytes());
+        format!("{}.{}", unsigned, signature)
+    }
+
+    pub fn verify_state(&self, state: &str) -> Result<(), StateError> {
+        self.verify_state_at(state, SystemTime::now())
+    }
+
+    /// Like [`verify_state`](Self::verify_state), but checks the expiry
+    /// against `now` rather than the system clock
+    pub fn verify_state_at(&self, state: &str, now: SystemTime) -> Result<(), StateError> {
+        let (unsigned, signature) = state.rsplit_once('.').ok_or(StateError::Malformed)?;
+        let (_, expires_at) = unsigned.split_once('.').ok_or(StateError::Malformed)?;
+        let expires_at: i64 = expires_at.parse().map_err(|_| StateError::Malformed)?;
+        let signature = hex::decode(signature).map_err(|_| StateError::Malformed)?;
+
+        self.state_mac(unsigned)
+            .verify_slice(&signature)
+            .map_err(|_| StateError::Mismatch)?;
+
+        if unix_time(now) > expires_at {
+            return Err(StateError::Expired);
+        }
+        Ok(())
+    }
+
+    /// Where to send the user to install the app
+    pub fn authorize_url(&self, state: &str) -> String {
+        let mut url = self.authorize_url.clone();
+
+        {
+            let mut query = url.query_pairs_mut();
+            query.append_pair("client_id", &self.client_id);
+            query.append_pair("scope", &self.scopes.join(","));
+            if !self.user_scopes.is_empty() {
+                query.append_pair("user_scope", &self.user_scopes.join(","));
+            }
+            if let Some(redirect_uri) = &self.redirect_uri {
+                query.append_pair("redirect_uri", redirect_uri);
+            }
+            query.append_pair("state", state);
+        }
+
+        url.into()
+    }
+
+    /// Handle the redirect back from Slack, given its query string (without
+    /// the leading `?`)
+    pub async fn handle_redirect(&self, query: &str) -> Result<Installation, Error> {
+        #[derive(Deserialize)]
+        struct Redirect {
+            code: Option<String>,
+            state: Option<String>,
+            error: Option<String>,
+        }
+
+        let redirect: Redirect =
+            serde_urlencoded::from_str(query).map_err(|_| Error::MissingCode)?;
+
+        self.verify_state(redirect.state.as_deref().unwrap_or_default())?;
+        if let Some(error) = redirect.error {
+            return Err(Error::Denied(error));
+        }
+        let code = redirect.code.ok_or(Error::MissingCode)?;
+
+        self.exchange_code(&code).await
+    }
+
+    /// Exchange a `code` for an [`Installation`], without checking `state`
+    pub async fn exchange_code(&self, code: &str) -> Result<Installation, Error> {
+        let request = OauthV2Access::builder()
+            .client_id(self.client_id.clone())
+            .client_secret(self.client_secret.clone())
+            .grant(Grant::AuthorizationCode {
+                code: code.to_owned(),
+                // Has to match the one in the authorize URL, if one was sent
+                redirect_uri: self.redirect_uri.clone(),
+            })
+            .build();
+
+        let response = self.client.call(&request).await?;
+        Ok(Installation::from_response(
+            response,
+            unix_time(SystemTime::now()),
+        ))
+    }
+
+    /// Find the installation that covers a request (see
+    /// [`InstallationStore::resolve`]), refreshing its tokens first if they're
+    /// about to expire. Each refreshed token is saved back to `store` as soon
+    /// as it's refreshed, so a failure refreshing the user token doesn't lose
+    /// a rotated bot token.
+    ///
+    /// Refresh tokens only work once, so concurrent lookups of the same
+    /// installation take turns: the first refreshes, and the rest pick up the
+    /// new tokens from `store`.
+    pub async fn find_installation<S>(
+        &self,
+        store: &S,
+        enterprise_id: Option<&str>,
+        team_id: Option<&str>,
+        is_enterprise_install: bool,
+    ) -> Result<Option<Installation>, Error>
+    where
+        S: InstallationStore + ?Sized,
+    {
+        let mut installation = match store.resolve(enterprise_id, team_id, is_enterprise_install)? {
+            Some(installation) => installation,
+            None => return Ok(None),
+        };
+        if !self.needs_refresh(&installation, unix_time(SystemTime::now())) {
+            return Ok(Some(installation));
+        }
+
+        let lock = self.refresh_lock(&installation);
+        let _guard = lock.lock().await;
+        // Someone else may have refreshed it while we waited
+        let (enterprise_id, team_id) = installation.key();
+        if let Some(latest) = store.find(enterprise_id, team_id)? {
+            installation = latest;
+        }
+
+        let now = unix_time(SystemTime::now());
+        if self.refresh_bot_token(&mut installation, now).await? {
+            store.save(installation.clone())?;
+        }
+        if self.refresh_user_token(&mut installation, now).await? {
+            store.save(installation.clone())?;
+        }
+        Ok(Some(installation))
+    }
+
+    /// Refresh the bot and user tokens if they expire within
+    /// `refresh_before`, returning whether either was refreshed. Tokens
+    /// without a `refresh_token` are left alone.
+    ///
+    /// The bot token is refreshed first. If the user token then fails,
+    /// `installation` still holds the new bot token, and should be saved
+    /// anyway: refresh tokens are single use, so the old one won't work again.
+    pub async fn refresh_tokens(&self, installation: &mut Installation) -> Result<bool, Error> {
+        let now = unix_time(SystemTime::now());
+        let bot = self.refresh_bot_token(installation, now).await?;
+        let user = self.refresh_user_token(installation, now).await?;
+        Ok(bot || user)
+    }
+
+    async fn refresh_bot_token(
+        &self,
+        installation: &mut Installation,
+        now: i64,
+    ) -> Result<bool, Error> {
+        let bot = match &mut installation.bot {
+            Some(bot) => bot,
+            None => return Ok(false),
+        };
+        let refresh_token = match self.due_refresh(&bot.refresh_token, bot.expires_at, now) {
+            Some(refresh_token) => refresh_token,
+            None => return Ok(false),
+        };
+
+        let response = self.refresh(refresh_token).await?;
+        bot.token = response.access_token.ok_or(Error::MissingToken)?;
+        bot.refresh_token = response.refresh_token.or_else(|| bot.refresh_token.take());
+        bot.expires_at = response.expires_in.map(|expires_in| now + expires_in);
+        Ok(true)
+    }
+
+    async fn refresh_user_token(
+        &self,
+        installation: &mut Installation,
+        now: i64,
+    ) -> Result<bool, Error> {
+        let user = &mut installation.user;
+        let refresh_token = match self.due_refresh(&user.refresh_token, user.expires_at, now) {
+            Some(refresh_token) => refresh_token,
+            None => return Ok(false),
+        };
+
+        let response = self.refresh(refresh_token).await?;
+        user.token = Some(response.access_token.ok_or(Error::MissingToken)?);
+        user.refresh_token = response.refresh_token.or_else(|| user.refresh_token.take());
+        user.expires_at = response.expires_in.map(|expires_in| now + expires_in);
+        Ok(true)
+    }
+
+    fn needs_refresh(&self, installation: &Installation, now: i64) -> bool {
+        let bot_due = match &installation.bot {
+            Some(bot) => self.due_refresh(&bot.refresh_token, bot.expires_at, now),
+            None => None,
+        };
+        let user = &installation.user;
+        bot_due.is_some()
+            || self
+                .due_refresh(&user.refresh_token, user.expires_at, now)
+                .is_some()
+    }
+
+    fn refresh_lock(&self, installation: &Installation) -> Arc<tokio::sync::Mutex<()>> {
+        let (enterprise_id, team_id) = installation.key();
+        let key = (enterprise_id.map(String::from), team_id.map(String::from));
+
+        let mut locks = self
+            .refresh_locks
+            .lock()
+            .unwrap_or_else(|err| err.into_inner());
+        // Forget the locks nobody's holding or waiting on
+        locks.retain(|_, lock| Arc::strong_count(lock) > 1);
+        locks.entry(key).or_default().clone()
+    }
+
+    /// The refresh token to use, if the token it goes with needs refreshing
+    fn due_refresh(
+        &self,
+        refresh_token: &Option<String>,
+        expires_at: Option<i64>,
+        now: i64,
+    ) -> Option<String> {
+        let expires_at = expires_at?;
+        if expires_at - (self.refresh_before.as_secs() as i64) > now {
+            return None;
+        }
+        refresh_token.clone()
+    }
+
+    async fn refresh(&self, refresh_token: String) -> Result<OauthV2AccessResponse, Error> {
+        let request = OauthV2Access::builder()
+            .client_id(self.client_id.clone())
+            .client_secret(self.client_secret.clone())
+            .grant(Grant::RefreshToken { refresh_token })
+            .build();
+
+        Ok(self.client.call(&request).await?)
+    }
+
+    fn state_mac(&self, unsigned: &str) -> Hmac<Sha256> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.state_secret.as_bytes())
+            .expect("HMAC accepts keys of any length");
+        mac.update(unsigned.as_bytes());
+        mac
+    }
+}
+
+impl fmt::Debug for InstallProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InstallProvider")
+            .field("client_id", &self.client_id)
+            .field("scopes", &self.scopes)
+            .field("user_scopes", &self.user_scopes)
+            .field("redirect_uri", &self.redirect_uri)
+            .finish()
+    }
+}
@@ -0,0 +1,264 @@
+//! Somewhere to keep [`Installation`]s, so the right token can be found for
+//! each incoming request
+//!
+//! Installations are keyed on their enterprise and team IDs. An org-wide
+//! install into an Enterprise Grid organization has no team, and covers every
+//! workspace in the organization, so [`InstallationStore::resolve`] falls
+//! back to it when a workspace has no installation of its own.
+//!
+//! ```
+//! # use slack_lib::oauth::Installation;
+//! # use slack_lib::oauth::store::*;
+//! # use serde_json::json;
+//! let store = MemoryStore::new();
+//!
+//! let org_wide: Installation = serde_json::from_value(json!({
+//!     "app_id": "A111",
+//!     "enterprise": { "id": "E111" },
+//!     "is_enterprise_install": true,
+//!     "user": { "id": "U111" },
+//!     "installed_at": 1610000000
+//! }))
+//! .unwrap();
+//! store.save(org_wide).unwrap();
+//!
+//! // A workspace in the organization
+//! let found = store.resolve(Some("E111"), Some("T222"), false).unwrap();
+//! assert_eq!(found.unwrap().enterprise_id(), Some("E111"));
+//!
+//! // A workspace outside it
+//! assert!(store.resolve(None, Some("T333"), false).unwrap().is_none());
+//! ```
+
+use super::Installation;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    /// The file a [`FileStore`] uses couldn't be parsed
+    Json(serde_json::Error),
+    /// For other implementations of [`InstallationStore`]
+    Other(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "could not access installations: {}", err),
+            Error::Json(err) => write!(f, "invalid installations file: {}", err),
+            Error::Other(err) => write!(f, "installation store error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            Error::Json(err) => Some(err),
+            Error::Other(err) => Some(&**err),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::Json(err)
+    }
+}
+
+/// Implement this to keep installations somewhere else, e.g. a database
+pub trait InstallationStore: Send + Sync {
+    /// Add an installation, replacing any with the same
+    /// [`key`](Installation::key)
+    fn save(&self, installation: Installation) -> Result<(), Error>;
+
+    /// Look up an installation by its exact [`key`](Installation::key)
+    fn find(
+        &self,
+        enterprise_id: Option<&str>,
+        team_id: Option<&str>,
+    ) -> Result<Option<Installation>, Error>;
+
+    fn delete(&self, enterprise_id: Option<&str>, team_id: Option<&str>) -> Result<(), Error>;
+
+    /// Find the installation that covers a request, using the IDs from its
+    /// payload. Workspaces without an installation of their own fall back to
+    /// their organization's org-wide install.
+    fn resolve(
+        &self,
+        enterprise_id: Option<&str>,
+        team_id: Option<&str>,
+        is_enterprise_install: bool,
+    ) -> Result<Option<Installation>, Error> {
+        if is_enterprise_install {
+            return self.find(enterprise_id, None);
+        }
+
+        if let Some(installation) = self.find(enterprise_id, team_id)? {
+            return Ok(Some(installation));
+        }
+
+        match enterprise_id {
+            Some(_) => self.find(enterprise_id, None),
+            None => Ok(None),
+        }
+    }
+}
+
+impl Installation {
+    /// `(enterprise_id, team_id)`. The team is left out for org-wide
+    /// installs.
+    pub fn key(&self) -> (Option<&str>, Option<&str>) {
+        if self.is_enterprise_install {
+            (self.enterprise_id(), None)
+        } else {
+            (self.enterprise_id(), self.team_id())
+        }
+    }
+}
+
+type Key = (Option<String>, Option<String>);
+
+fn owned_key(enterprise_id: Option<&str>, team_id: Option<&str>) -> Key {
+    (enterprise_id.map(String::from), team_id.map(String::from))
+}
+
+/// Keeps installations in memory, so they're lost on restart
+#[derive(Debug, Default)]
+pub struct MemoryStore {
+    installations: Mutex<HashMap<Key, Installation>>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl InstallationStore for MemoryStore {
+    fn save(&self, installation: Installation) -> Result<(), Error> {
+        let (enterprise_id, team_id) = installation.key();
+        let key = owned_key(enterprise_id, team_id);
+        self.installations
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .insert(key, installation);
+        Ok(())
+    }
+
+    fn find(
+        &self,
+        enterprise_id: Option<&str>,
+        team_id: Option<&str>,
+    ) -> Result<Option<Installation>, Error> {
+        Ok(self
+            .installations
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .get(&owned_key(enterprise_id, team_id))
+            .cloned())
+    }
+
+    fn delete(&self, enterprise_id: Option<&str>, team_id: Option<&str>) -> Result<(), Error> {
+        self.installations
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .remove(&owned_key(enterprise_id, team_id));
+        Ok(())
+    }
+}
+
+/// Keeps installations in a JSON file, as an array. The file is created on
+/// the first save, and rewritten (via a temporary file alongside it) on every
+/// change.
+///
+/// Only one `FileStore` should use a file at a time.
+#[derive(Debug)]
+pub struct FileStore {
+    path: PathBuf,
+    lock: Mutex<()>,
+}
+
+impl FileStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            lock: Mutex::new(()),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn read(&self) -> Result<Vec<Installation>, Error> {
+        match fs::read(&self.path) {
+            Ok(contents) => Ok(serde_json::from_slice(&contents)?),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn write(&self, installations: &[Installation]) -> Result<(), Error> {
+        let mut temporary = self.path.clone().into_os_string();
+        temporary.push(".tmp");
+
+        fs::write(&temporary, serde_json::to_vec_pretty(installations)?)?;
+        fs::rename(&temporary, &self.path)?;
+        Ok(())
+    }
+}
+
+impl InstallationStore for FileStore {
+    fn save(&self, installation: Installation) -> Result<(), Error> {
+        let _guard = self.lock.lock().unwrap_or_else(|err| err.into_inner());
+        let mut installations = self.read()?;
+
+        match installations
+            .iter_mut()
+            .find(|existing| existing.key() == installation.key())
+        {
+            Some(existing) => *existing = installation,
+            None => installations.push(installation),
+        }
+
+        self.write(&installations)
+    }
+
+    fn find(
+        &self,
+        enterprise_id: Option<&str>,
+        team_id: Option<&str>,
+    ) -> Result<Option<Installation>, Error> {
+        let _guard = self.lock.lock().unwrap_or_else(|err| err.into_inner());
+        Ok(self
+            .read()?
+            .into_iter()
+            .find(|installation| installation.key() == (enterprise_id, team_id)))
+    }
+
+    fn delete(&self, enterprise_id: Option<&str>, team_id: Option<&str>) -> Result<(), Error> {
+        let _guard = self.lock.lock().unwrap_or_else(|err| err.into_inner());
+        let mut installations = self.read()?;
+        let before = installations.len();
+        installations.retain(|installation| installation.key() != (enterprise_id, team_id));
+
+        if installations.len() == before {
+            return Ok(());
+        }
+        self.write(&installations)
+    }
+}
@@ -0,0 +1,424 @@
+//! [https://api.slack.com/authentication/sign-in-with-slack](https://api.slack.com/authentication/sign-in-with-slack)
+//!
+//! Sign in with Slack is OpenID Connect:
+//!
+//! 1. Generate a `state` and `nonce`, keep them (e.g. in a cookie), and send
+//!    the user to [`SignInProvider::authorize_url`]
+//! 2. When they come back, check the `state` matches and pass the `code` to
+//!    [`SignInProvider::exchange_code`]
+//! 3. Check the ID token with an [`IdTokenValidator`] (which also checks the
+//!    `nonce`) to get the user's [`Claims`]
+//!
+//! The keys to check ID tokens against are published at [`JWKS_URL`].
+//!
+//! ```no_run
+//! # use slack_lib::openid::*;
+//! # async fn run(code: &str, jwks: Jwks) -> Result<(), Box<dyn std::error::Error>> {
+//! let provider = SignInProvider::builder()
+//!     .client_id("25259531569.1115258246291")
+//!     .client_secret("33fea0113f5b1")
+//!     .redirect_uri("https://example.com/slack/sign_in")
+//!     .build();
+//!
+//! let (state, nonce) = (generate_state(), generate_nonce());
+//! let url = provider.authorize_url(&state, &nonce);
+//!
+//! // ...and once the user has been redirected back with a `code`
+//! let tokens = provider.exchange_code(code).await?;
+//! let claims = IdTokenValidator::builder()
+//!     .client_id("25259531569.1115258246291")
+//!     .nonce(nonce)
+//!     .build()
+//!     .validate(&tokens.id_token, &jwks)?;
+//!
+//! println!("signed in as {} in {}", claims.user_id, claims.team_id);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::util::{random_hex, unix_time};
+use crate::web_api::oauth::Grant;
+use crate::web_api::openid::{
+    OpenidConnectToken, OpenidConnectTokenResponse, OpenidConnectUserInfo,
+    OpenidConnectUserInfoResponse,
+};
+use crate::web_api::{self, Client};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use ring::signature::{RsaPublicKeyComponents, RSA_PKCS1_2048_8192_SHA256};
+use serde::Deserializer;
+use std::fmt;
+use std::time::{Duration, SystemTime};
+
+pub const AUTHORIZE_URL: &str = "https://slack.com/openid/connect/authorize";
+pub const JWKS_URL: &str = "https://slack.com/openid/connect/keys";
+pub const ISSUER: &str = "https://slack.com";
+
+/// A random `state`, to check the redirect back came from a sign in this app
+/// started
+pub fn generate_state() -> String {
+    random_hex(16)
+}
+
+/// A random `nonce`, to tie the ID token to this sign in
+pub fn generate_nonce() -> String {
+    random_hex(16)
+}
+
+/// Create a builder with [`SignInProvider::builder()`]
+///
+/// Available fields:
+///
+/// - `.client_id(`[`Into`]`<`[`String`]`>)` - required
+/// - `.client_secret(`[`Into`]`<`[`String`]`>)` - required
+/// - `.redirect_uri(`[`Into`]`<`[`String`]`>)` - required
+/// - `.scopes(`[`Vec`]`<`[`String`]`>)` - optional, defaults to `openid`,
+///   `email` and `profile`
+/// - `.authorize_url(`[`Url`](reqwest::Url)`)` - optional
+/// - `.client(`[`Client`]`)` - optional, used to call `openid.connect.*`
+///
+/// Finalise the builder with `.build()` to retrieve a [`SignInProvider`]
+///
+/// ```
+/// # use slack_lib::openid::*;
+/// let provider = SignInProvider::builder()
+///     .client_id("25259531569.1115258246291")
+///     .client_secret("33fea0113f5b1")
+///     .redirect_uri("https://example.com/slack/sign_in")
+///     .build();
+///
+/// let url = provider.authorize_url("af0ifjsldkj", "n-0S6_WzA2Mj");
+/// assert_eq!(
+///     url,
+///     "https://slack.com/openid/connect/authorize?response_type=code\
+///      &scope=openid+email+profile&client_id=25259531569.1115258246291\
+///      &state=af0ifjsldkj&nonce=n-0S6_WzA2Mj\
+///      &redirect_uri=https%3A%2F%2Fexample.com%2Fslack%2Fsign_in"
+/// );
+/// ```
+#[derive(TypedBuilder, Clone)]
+pub struct SignInProvider {
+    #[builder(setter(into))]
+    client_id: String,
+
+    #[builder(setter(into))]
+    client_secret: String,
+
+    #[builder(setter(into))]
+    redirect_uri: String,
+
+    #[builder(default = vec![
+        String::from("openid"),
+        String::from("email"),
+        String::from("profile"),
+    ])]
+    scopes: Vec<String>,
+
+    #[builder(default = reqwest::Url::parse(AUTHORIZE_URL).expect("AUTHORIZE_URL is valid"))]
+    authorize_url: reqwest::Url,
+
+    #[builder(default = Client::new(""))]
+    client: Client,
+}
+
+impl SignInProvider {
+    /// Where to send the user to sign in
+    pub fn authorize_url(&self, state: &str, nonce: &str) -> String {
+        let mut url = self.authorize_url.clone();
+
+        url.query_pairs_mut()
+            .append_pair("response_type", "code")
+            .append_pair("scope", &self.scopes.join(" "))
+            .append_pair("client_id", &self.client_id)
+            .append_pair("state", state)
+            .append_pair("nonce", nonce)
+            .append_pair("redirect_uri", &self.redirect_uri);
+
+        url.into()
+    }
+
+    /// Exchange the `code` from the redirect for a user token and ID token
+    pub async fn exchange_code(
+        &self,
+        code: &str,
+    ) -> Result<OpenidConnectTokenResponse, web_api::Error> {
+        let request = OpenidConnectToken::builder()
+            .client_id(self.client_id.clone())
+            .client_secret(self.client_secret.clone())
+            .grant(Grant::AuthorizationCode {
+                code: code.to_owned(),
+                redirect_uri: Some(self.redirect_uri.clone()),
+            })
+            .build();
+
+        self.client.call(&request).await
+    }
+
+    /// Look up the signed in user, using the `access_token` from
+    /// [`exchange_code`](Self::exchange_code)
+    pub async fn user_info(
+        &self,
+        access_token: &str,
+    ) -> Result<OpenidConnectUserInfoResponse, web_api::Error> {
+        self.client
+            .with_token(access_token)
+            .call(&OpenidConnectUserInfo::default())
+            .await
+    }
+}
+
+impl fmt::Debug for SignInProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SignInProvider")
+            .field("client_id", &self.client_id)
+            .field("redirect_uri", &self.redirect_uri)
+            .field("scopes", &self.scopes)
+            .finish()
+    }
+}
+
+/// A JSON Web Key Set, as published at [`JWKS_URL`]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct Jwks {
+    pub keys: Vec<Jwk>,
+}
+
+impl Jwks {
+    /// The key with ID `kid`. Without a `kid`, the only key in the set.
+    pub fn find(&self, kid: Option<&str>) -> Option<&Jwk> {
+        match kid {
+            Some(kid) => self.keys.iter().find(|key| key.kid.as_deref() == Some(kid)),
+            None if self.keys.len() == 1 => self.keys.first(),
+            None => None,
+        }
+    }
+}
+
+/// An RSA public key
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct Jwk {
+    /// Always `RSA` for Slack
+    pub kty: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alg: Option<String>,
+    #[serde(default, rename = "use", skip_serializing_if = "Option::is_none")]
+    pub key_use: Option<String>,
+    /// Modulus, base64url encoded
+    pub n: String,
+    /// Exponent, base64url encoded
+    pub e: String,
+}
+
+/// The claims in an ID token
+///
+/// The `email` fields are only set with the `email` scope, and the name and
+/// picture fields with the `profile` scope.
+#[derive(Deserialize, Debug, Clone)]
+pub struct Claims {
+    pub iss: String,
+    /// The user ID
+    pub sub: String,
+    /// Usually just the client ID
+    #[serde(deserialize_with = "deserialize_audience")]
+    pub aud: Vec<String>,
+    /// Unix timestamp
+    pub exp: i64,
+    /// Unix timestamp
+    pub iat: i64,
+    #[serde(default)]
+    pub auth_time: Option<i64>,
+    #[serde(default)]
+    pub nonce: Option<String>,
+    #[serde(default)]
+    pub at_hash: Option<String>,
+    #[serde(rename = "https://slack.com/team_id")]
+    pub team_id: String,
+    #[serde(rename = "https://slack.com/user_id")]
+    pub user_id: String,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub email_verified: Option<bool>,
+    #[serde(default)]
+    pub date_email_verified: Option<i64>,
+    #[serde(default)]
+    pub locale: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub picture: Option<String>,
+    #[serde(default)]
+    pub given_name: Option<String>,
+    #[serde(default)]
+    pub family_name: Option<String>,
+    #[serde(default, rename = "https://slack.com/team_name")]
+    pub team_name: Option<String>,
+    #[serde(default, rename = "https://slack.com/team_domain")]
+    pub team_domain: Option<String>,
+}
+
+/// `aud` may be a single string or an array of them
+fn deserialize_audience<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Audience {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match serde::Deserialize::deserialize(deserializer)? {
+        Audience::One(audience) => vec![audience],
+        Audience::Many(audiences) => audiences,
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// Not a JWT, or its header or claims couldn't be parsed
+    Malformed(&'static str),
+    /// Only `RS256` is supported, which is what Slack uses
+    UnsupportedAlgorithm(String),
+    /// No key in the set matches the token's `kid`
+    UnknownKey(Option<String>),
+    /// The matching key isn't a usable RSA key
+    InvalidKey,
+    /// The signature doesn't match
+    Mismatch,
+    /// Issued by someone other than the expected issuer
+    Issuer(String),
+    /// Not issued for this client ID
+    Audience,
+    Expired {
+        exp: i64,
+    },
+    /// Missing, or different from the `nonce` sent with the sign in
+    Nonce,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Malformed(part) => write!(f, "malformed ID token {}", part),
+            Error::UnsupportedAlgorithm(alg) => write!(f, "unsupported algorithm {}", alg),
+            Error::UnknownKey(Some(kid)) => write!(f, "no key with ID {}", kid),
+            Error::UnknownKey(None) => f.write_str("ID token has no key ID"),
+            Error::InvalidKey => f.write_str("key is not a valid RSA public key"),
+            Error::Mismatch => f.write_str("ID token signature does not match"),
+            Error::Issuer(iss) => write!(f, "ID token issued by {}", iss),
+            Error::Audience => f.write_str("ID token is for a different client"),
+            Error::Expired { exp } => write!(f, "ID token expired at {}", exp),
+            Error::Nonce => f.write_str("ID token nonce does not match"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Checks an ID token's signature and claims
+///
+/// Create a builder with [`IdTokenValidator::builder()`]
+///
+/// Available fields:
+///
+/// - `.client_id(`[`Into`]`<`[`String`]`>)` - required, checked against `aud`
+/// - `.nonce(`[`Into`]`<`[`String`]`>)` - optional, but should be set to the
+///   `nonce` sent with the sign in
+/// - `.issuer(`[`Into`]`<`[`String`]`>)` - optional, defaults to [`ISSUER`]
+/// - `.leeway(`[`Duration`]`)` - optional, allowance for clock skew when
+///   checking `exp`. Defaults to a minute.
+///
+/// Finalise the builder with `.build()` to retrieve an [`IdTokenValidator`]
+#[derive(TypedBuilder, Debug, Clone)]
+pub struct IdTokenValidator {
+    #[builder(setter(into))]
+    client_id: String,
+
+    #[builder(setter(into, strip_option), default)]
+    nonce: Option<String>,
+
+    #[builder(setter(into), default = String::from(ISSUER))]
+    issuer: String,
+
+    #[builder(default = Duration::from_secs(60))]
+    leeway: Duration,
+}
+
+impl IdTokenValidator {
+    pub fn validate(&self, id_token: &str, jwks: &Jwks) -> Result<Claims, Error> {
+        self.validate_at(id_token, jwks, SystemTime::now())
+    }
+
+    /// Like [`validate`](Self::validate), but checks the expiry against
+    /// `now` rather than the system clock
+    pub fn validate_at(
+        &self,
+        id_token: &str,
+        jwks: &Jwks,
+        now: SystemTime,
+    ) -> Result<Claims, Error> {
+        #[derive(Deserialize)]
+        struct Header {
+            alg: String,
+            #[serde(default)]
+            kid: Option<String>,
+        }
+
+        let mut parts = id_token.split('.');
+        let (header, claims, signature) =
+            match (parts.next(), parts.next(), parts.next(), parts.next()) {
+                (Some(header), Some(claims), Some(signature), None) => (header, claims, signature),
+                _ => return Err(Error::Malformed("token")),
+            };
+        let signed = &id_token[..header.len() + 1 + claims.len()];
+
+        let header: Header = decode_json(header).ok_or(Error::Malformed("header"))?;
+        if header.alg != "RS256" {
+            return Err(Error::UnsupportedAlgorithm(header.alg));
+        }
+
+        let key = jwks
+            .find(header.kid.as_deref())
+            .ok_or(Error::UnknownKey(header.kid))?;
+        if key.kty != "RSA" {
+            return Err(Error::InvalidKey);
+        }
+        let n = decode(&key.n).ok_or(Error::InvalidKey)?;
+        let e = decode(&key.e).ok_or(Error::InvalidKey)?;
+        let signature = decode(signature).ok_or(Error::Malformed("signature"))?;
+
+        RsaPublicKeyComponents { n: &n, e: &e }
+            .verify(&RSA_PKCS1_2048_8192_SHA256, signed.as_bytes(), &signature)
+            .map_err(|_| Error::Mismatch)?;
+
+        let claims: Claims = decode_json(claims).ok_or(Error::Malformed("claims"))?;
+
+        if claims.iss != self.issuer {
+            return Err(Error::Issuer(claims.iss));
+        }
+        if !claims.aud.contains(&self.client_id) {
+            return Err(Error::Audience);
+        }
+        if claims.exp + (self.leeway.as_secs() as i64) < unix_time(now) {
+            return Err(Error::Expired { exp: claims.exp });
+        }
+        if let Some(nonce) = &self.nonce {
+            if claims.nonce.as_ref() != Some(nonce) {
+                return Err(Error::Nonce);
+            }
+        }
+
+        Ok(claims)
+    }
+}
+
+fn decode(part: &str) -> Option<Vec<u8>> {
+    URL_SAFE_NO_PAD.decode(part.trim_end_matches('=')).ok()
+}
+
+fn decode_json<T: serde::de::DeserializeOwned>(part: &str) -> Option<T> {
+    serde_json::from_slice(&decode(part)?).ok()
+}
@@ -0,0 +1,188 @@
+//! [https://api.slack.com/interactivity/handling#message_responses](https://api.slack.com/interactivity/handling#message_responses)
+//!
+//! Slash commands and interactions in messages come with a `response_url`,
+//! which can be used to reply up to five times in the 30 minutes after the
+//! request. [`ResponseUrl`] keeps track of both limits, so a reply that Slack
+//! would reject fails before it's sent.
+//!
+//! Replies take the same shape as an immediate response to a slash command,
+//! so they're sent as a [`SlashCommandResponse`] whatever the `response_url`
+//! came with.
+//!
+//! ```no_run
+//! # use slack_lib::response_url::*;
+//! # use slack_lib::slash_commands::*;
+//! # async fn run(command: SlashCommand) -> Result<(), Error> {
+//! let response_url = ResponseUrl::new(command.response_url);
+//!
+//! response_url
+//!     .send(
+//!         &SlashCommandResponse::builder()
+//!             .response_type(ResponseType::InChannel)
+//!             .content("Deploy finished")
+//!             .build(),
+//!     )
+//!     .await?;
+//!
+//! assert_eq!(response_url.remaining_uses(), 4);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::slash_commands::SlashCommandResponse;
+use std::fmt;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::{Duration, SystemTime};
+
+/// How many times a `response_url` can be used
+pub const MAX_USES: u32 = 5;
+
+/// How long a `response_url` can be used for
+pub const LIFETIME: Duration = Duration::from_secs(30 * 60);
+
+#[derive(Debug)]
+pub enum Error {
+    /// More than 30 minutes have passed since the `response_url` was issued
+    Expired,
+    /// The `response_url` has already been used five times
+    UsesExhausted,
+    /// The request never made it, or the response couldn't be read
+    Http(reqwest::Error),
+    /// Slack rejected the response. `error` is the error code, e.g.
+    /// `no_text`, or the body of the response if it wasn't JSON
+    Api { status: u16, error: String },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Expired => f.write_str("response_url has expired"),
+            Error::UsesExhausted => write!(f, "response_url was already used {} times", MAX_USES),
+            Error::Http(err) => write!(f, "http error: {}", err),
+            Error::Api { status, error } => write!(f, "response rejected ({}): {}", status, error),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Http(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Self {
+        Error::Http(err)
+    }
+}
+
+/// Sends replies to a `response_url`, keeping count of how many are left
+#[derive(Debug)]
+pub struct ResponseUrl {
+    http: reqwest::Client,
+    url: String,
+    issued_at: SystemTime,
+    uses: AtomicU32,
+}
+
+impl ResponseUrl {
+    /// Assumes the `response_url` was issued just now. Use
+    /// [`issued_at`](Self::issued_at) if it wasn't.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self::with_http_client(reqwest::Client::new(), url)
+    }
+
+    /// Use an existing [`reqwest::Client`], e.g. the one a
+    /// [`Client`](crate::web_api::Client) was created with
+    pub fn with_http_client(http: reqwest::Client, url: impl Into<String>) -> Self {
+        Self {
+            http,
+            url: url.into(),
+            issued_at: SystemTime::now(),
+            uses: AtomicU32::new(0),
+        }
+    }
+
+    /// When the request carrying the `response_url` was received
+    pub fn issued_at(mut self, issued_at: SystemTime) -> Self {
+        self.issued_at = issued_at;
+        self
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn expires_at(&self) -> SystemTime {
+        self.issued_at + LIFETIME
+    }
+
+    pub fn is_expired(&self) -> bool {
+        SystemTime::now() >= self.expires_at()
+    }
+
+    /// How many more times this can be sent to. Failed sends still count,
+    /// since Slack counts them too.
+    pub fn remaining_uses(&self) -> u32 {
+        MAX_USES.saturating_sub(self.uses.load(Ordering::SeqCst))
+    }
+
+    /// Send a reply.
+    ///
+    /// By default, replies are ephemeral and are posted as a new message. Set
+    /// `replace_original` to update the message the interaction came from
+    /// instead.
+    pub async fn send(&self, message: &SlashCommandResponse) -> Result<(), Error> {
+        if self.is_expired() {
+            return Err(Error::Expired);
+        }
+
+        self.uses
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |uses| {
+                if uses < MAX_USES {
+                    Some(uses + 1)
+                } else {
+                    None
+                }
+            })
+            .map_err(|_| Error::UsesExhausted)?;
+
+        let response = self.http.post(&self.url).json(message).send().await?;
+        let status = response.status();
+        let body = response.text().await?;
+
+        // Slack has responded with both `ok` and `{"ok": true}` over the years
+        let error = match serde_json::from_str::<serde_json::Value>(&body) {
+            Ok(json) if json.get("ok").and_then(serde_json::Value::as_bool) == Some(true) => None,
+            Ok(json) => Some(
+                json.get("error")
+                    .and_then(serde_json::Value::as_str)
+                    .unwrap_or("unknown_error")
+                    .to_owned(),
+            ),
+            Err(_) if status.is_success() && body.trim() == "ok" => None,
+            Err(_) => Some(body),
+        };
+
+        match error {
+            None if status.is_success() => Ok(()),
+            error => Err(Error::Api {
+                status: status.as_u16(),
+                error: error.unwrap_or_else(|| String::from("unknown_error")),
+            }),
+        }
+    }
+
+    /// Delete the message the interaction came from
+    pub async fn delete_original(&self) -> Result<(), Error> {
+        self.send(
+            &SlashCommandResponse::builder()
+                .delete_original(true)
+                .build(),
+        )
+        .await
+    }
+}
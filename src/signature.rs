@@ -0,0 +1,216 @@
+//! [https://api.slack.com/authentication/verifying-requests-from-slack](https://api.slack.com/authentication/verifying-requests-from-slack)
+//!
+//! Every request Slack sends to an app (events, interactivity, slash commands,
+//! options loads) is signed with the app's signing secret. Check it with a
+//! [`SignatureVerifier`] before trusting anything in the body.
+//!
+//! The verifier doesn't depend on any web framework: give it the raw body,
+//! exactly as it was received, along with the two headers.
+//!
+//! ```
+//! # use slack_lib::signature::*;
+//! # use std::time::{Duration, UNIX_EPOCH};
+//! let verifier = SignatureVerifier::new("8f742231b10e8888abcd99yyyzzz85a5");
+//!
+//! let body = "token=xyzz0WbapA4vBCDEFasx0q6G&team_id=T1DC2JH3J&team_domain=testteamnow&channel_id=G8PSS9T3V&channel_name=foobar&user_id=U2CERLKJA&user_name=roadrunner&command=%2Fwebhook-collect&text=&response_url=https%3A%2F%2Fhooks.slack.com%2Fcommands%2FT1DC2JH3J%2F397700885554%2F96rGlfmibIGlgcZRskXaIFfN&trigger_id=398738663015.47445629121.803a0bc887a14d10d2c447fce8b6703c";
+//! let timestamp = "1531420618";
+//! let signature = "v0=a2114d57b48eac39b9ad189dd8316235a7b4a8d21a10bd27519666489c69b503";
+//!
+//! let now = UNIX_EPOCH + Duration::from_secs(1531420618 + 10);
+//! assert!(verifier
+//!     .verify_at(timestamp, signature, body.as_bytes(), now)
+//!     .is_ok());
+//!
+//! // Tampering with the body is caught
+//! assert_eq!(
+//!     verifier.verify_at(timestamp, signature, b"token=forged", now),
+//!     Err(Error::Mismatch)
+//! );
+//!
+//! // As is replaying the request later on
+//! let later = UNIX_EPOCH + Duration::from_secs(1531420618 + 60 * 60);
+//! assert!(matches!(
+//!     verifier.verify_at(timestamp, signature, body.as_bytes(), later),
+//!     Err(Error::Expired { .. })
+//! ));
+//! ```
+
+use hmac::{Hmac, Mac};
+use reqwest::header::HeaderMap;
+use sha2::Sha256;
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const SIGNATURE_HEADER: &str = "x-slack-signature";
+pub const TIMESTAMP_HEADER: &str = "x-slack-request-timestamp";
+
+/// The only version of the signature scheme Slack has used so far
+const VERSION: &str = "v0";
+
+/// Slack recommends rejecting anything older than five minutes
+const DEFAULT_MAX_AGE: Duration = Duration::from_secs(60 * 5);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A header was missing, or wasn't in the format Slack uses
+    Malformed(&'static str),
+    /// The timestamp is too far from the current time, so the request may be
+    /// a replay
+    Expired { timestamp: i64 },
+    /// The signature doesn't match the body. Either the request didn't come
+    /// from Slack, or the signing secret is wrong.
+    Mismatch,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Malformed(reason) => write!(f, "malformed request signature: {}", reason),
+            Error::Expired { timestamp } => {
+                write!(
+                    f,
+                    "request timestamp {} is outside the allowed window",
+                    timestamp
+                )
+            }
+            Error::Mismatch => f.write_str("request signature does not match"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Verifies the `X-Slack-Signature` of inbound requests
+#[derive(Clone)]
+pub struct SignatureVerifier {
+    signing_secret: String,
+    max_age: Duration,
+}
+
+impl fmt::Debug for SignatureVerifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SignatureVerifier")
+            .field("signing_secret", &"..")
+            .field("max_age", &self.max_age)
+            .finish()
+    }
+}
+
+impl SignatureVerifier {
+    /// The signing secret is on the app's "Basic Information" page. It's not
+    /// the same as the (deprecated) verification token.
+    pub fn new(signing_secret: impl Into<String>) -> Self {
+        SignatureVerifier {
+            signing_secret: signing_secret.into(),
+            max_age: DEFAULT_MAX_AGE,
+        }
+    }
+
+    /// How far the request timestamp may be from the current time, in either
+    /// direction. Defaults to five minutes.
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    /// Verify a request using its headers. Header names are matched case
+    /// insensitively.
+    ///
+    /// ```
+    /// # use slack_lib::signature::*;
+    /// # use reqwest::header::HeaderMap;
+    /// let verifier = SignatureVerifier::new("secret");
+    ///
+    /// assert_eq!(
+    ///     verifier.verify_headers(&HeaderMap::new(), b"{}"),
+    ///     Err(Error::Malformed("missing X-Slack-Request-Timestamp header"))
+    /// );
+    /// ```
+    pub fn verify_headers(&self, headers: &HeaderMap, body: &[u8]) -> Result<(), Error> {
+        let timestamp = headers
+            .get(TIMESTAMP_HEADER)
+            .ok_or(Error::Malformed("missing X-Slack-Request-Timestamp header"))?
+            .to_str()
+            .map_err(|_| Error::Malformed("X-Slack-Request-Timestamp is not ASCII"))?;
+        let signature = headers
+            .get(SIGNATURE_HEADER)
+            .ok_or(Error::Malformed("missing X-Slack-Signature header"))?
+            .to_str()
+            .map_err(|_| Error::Malformed("X-Slack-Signature is not ASCII"))?;
+
+        self.verify(timestamp, signature, body)
+    }
+
+    /// Verify a request from the values of the `X-Slack-Request-Timestamp`
+    /// and `X-Slack-Signature` headers
+    pub fn verify(&self, timestamp: &str, signature: &str, body: &[u8]) -> Result<(), Error> {
+        self.verify_at(timestamp, signature, body, SystemTime::now())
+    }
+
+    /// Like [`verify`](Self::verify), but checks the timestamp against `now`
+    /// rather than the system clock
+    pub fn verify_at(
+        &self,
+        timestamp: &str,
+        signature: &str,
+        body: &[u8],
+        now: SystemTime,
+    ) -> Result<(), Error> {
+        let parsed: i64 = timestamp
+            .parse()
+            .map_err(|_| Error::Malformed("timestamp is not a number"))?;
+
+        let now = now
+            .duration_since(UNIX_EPOCH)
+            .map(|now| now.as_secs() as i64)
+            .unwrap_or(0);
+        // The timestamp comes straight from the request, so it could be
+        // anything, and subtracting could overflow
+        if now.abs_diff(parsed) > self.max_age.as_secs() {
+            return Err(Error::Expired { timestamp: parsed });
+        }
+
+        let digest = signature
+            .strip_prefix(VERSION)
+            .and_then(|rest| rest.strip_prefix('='))
+            .ok_or(Error::Malformed("signature is not a v0 signature"))?;
+        let digest =
+            hex::decode(digest).map_err(|_| Error::Malformed("signature is not valid hex"))?;
+
+        // `verify_slice` compares in constant time
+        self.mac(timestamp, body)
+            .verify_slice(&digest)
+            .map_err(|_| Error::Mismatch)
+    }
+
+    /// The `X-Slack-Signature` Slack would send for this timestamp and body.
+    /// Handy for testing handlers.
+    ///
+    /// ```
+    /// # use slack_lib::signature::*;
+    /// let verifier = SignatureVerifier::new("secret");
+    /// let timestamp = std::time::SystemTime::now()
+    ///     .duration_since(std::time::UNIX_EPOCH)
+    ///     .unwrap()
+    ///     .as_secs()
+    ///     .to_string();
+    ///
+    /// let signature = verifier.sign(&timestamp, b"payload");
+    /// assert!(verifier.verify(&timestamp, &signature, b"payload").is_ok());
+    /// ```
+    pub fn sign(&self, timestamp: &str, body: &[u8]) -> String {
+        let digest = self.mac(timestamp, body).finalize().into_bytes();
+        format!("{}={}", VERSION, hex::encode(digest))
+    }
+
+    fn mac(&self, timestamp: &str, body: &[u8]) -> Hmac<Sha256> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.signing_secret.as_bytes())
+            .expect("HMAC accepts keys of any length");
+        mac.update(VERSION.as_bytes());
+        mac.update(b":");
+        mac.update(timestamp.as_bytes());
+        mac.update(b":");
+        mac.update(body);
+        mac
+    }
+}
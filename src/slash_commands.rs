@@ -0,0 +1,216 @@
+//! [https://api.slack.com/interactivity/slash-commands](https://api.slack.com/interactivity/slash-commands)
+//!
+//! Slack POSTs slash commands to the command's request URL as a form body.
+//! Parse it with [`SlashCommand::from_form`], and reply (within three seconds)
+//! with a [`SlashCommandResponse`] as JSON.
+//!
+//! ```
+//! # use assert_json_diff::assert_json_eq;
+//! # use serde_json::json;
+//! # use slack_lib::slash_commands::*;
+//! let body = "token=gIkuvaNzQIHg97ATvDxqgjtO&team_id=T0001&team_domain=example&enterprise_id=E0001&enterprise_name=Globular%20Construct%20Inc&channel_id=C2147483705&channel_name=test&is_enterprise_install=false&user_id=U2147483697&user_name=Steve&command=%2Fweather&text=94070&response_url=https%3A%2F%2Fhooks.slack.com%2Fcommands%2F1234%2F5678&trigger_id=13345224609.738474920.8088930838d88f008e0&api_app_id=A123456";
+//!
+//! let command = SlashCommand::from_form(body).unwrap();
+//! assert_eq!(command.command, "/weather");
+//! assert_eq!(command.text, "94070");
+//! assert_eq!(command.enterprise_name.as_deref(), Some("Globular Construct Inc"));
+//! assert!(!command.is_enterprise_install);
+//!
+//! let response = SlashCommandResponse::builder()
+//!     .response_type(ResponseType::InChannel)
+//!     .content("It's 80 degrees right now.")
+//!     .build();
+//!
+//! let expected = json!({
+//!   "response_type": "in_channel",
+//!   "text": "It's 80 degrees right now."
+//! });
+//!
+//! let output = serde_json::to_value(&response).unwrap();
+//! assert_json_eq!(expected, output);
+//! ```
+
+use crate::block_kit::blocks::MessageBlock;
+
+/// [https://api.slack.com/interactivity/slash-commands#app_command_handling](https://api.slack.com/interactivity/slash-commands#app_command_handling)
+#[derive(Deserialize, Debug, Clone)]
+pub struct SlashCommand {
+    /// Deprecated in favour of request signing
+    #[serde(default)]
+    pub token: String,
+    /// The command that was typed, e.g. `/weather`
+    pub command: String,
+    /// Everything after the command. May be empty.
+    #[serde(default)]
+    pub text: String,
+    pub user_id: String,
+    #[serde(default)]
+    pub user_name: Option<String>,
+    pub channel_id: String,
+    #[serde(default)]
+    pub channel_name: Option<String>,
+    pub team_id: String,
+    #[serde(default)]
+    pub team_domain: Option<String>,
+    #[serde(default)]
+    pub enterprise_id: Option<String>,
+    #[serde(default)]
+    pub enterprise_name: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_bool_string")]
+    pub is_enterprise_install: bool,
+    #[serde(default)]
+    pub api_app_id: Option<String>,
+    /// Use this to open a modal within three seconds
+    pub trigger_id: String,
+    /// Post up to five more responses to this within 30 minutes
+    pub response_url: String,
+}
+
+impl SlashCommand {
+    /// Parse the form body of a slash command request
+    pub fn from_form(body: &str) -> Result<Self, serde_urlencoded::de::Error> {
+        serde_urlencoded::from_str(body)
+    }
+}
+
+/// Form bodies only have strings, and Socket Mode sends the same fields as
+/// JSON strings, so `"true"` needs to be accepted as well as `true`
+fn deserialize_bool_string<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum BoolOrString {
+        Bool(bool),
+        String(String),
+    }
+
+    match serde::Deserialize::deserialize(deserializer)? {
+        BoolOrString::Bool(value) => Ok(value),
+        BoolOrString::String(value) => Ok(value == "true"),
+    }
+}
+
+/// Who sees a response
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ResponseType {
+    /// Everyone in the channel, along with the original command
+    InChannel,
+    /// Only the user who ran the command. This is the default.
+    Ephemeral,
+}
+
+/// What a message says. Slack needs `text`, `blocks`, or both.
+///
+/// Strings convert into [`MessageContent::Text`], and a `Vec<MessageBlock>`
+/// into [`MessageContent::Blocks`] with no notification text.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum MessageContent {
+    Blocks {
+        blocks: Vec<MessageBlock>,
+        /// Shown in notifications, and by clients that can't show blocks
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        text: Option<String>,
+    },
+    Text {
+        text: String,
+    },
+}
+
+impl From<&str> for MessageContent {
+    fn from(text: &str) -> Self {
+        MessageContent::Text {
+            text: text.to_owned(),
+        }
+    }
+}
+
+impl From<String> for MessageContent {
+    fn from(text: String) -> Self {
+        MessageContent::Text { text }
+    }
+}
+
+impl From<Vec<MessageBlock>> for MessageContent {
+    fn from(blocks: Vec<MessageBlock>) -> Self {
+        MessageContent::Blocks { blocks, text: None }
+    }
+}
+
+/// [https://api.slack.com/interactivity/handling#message_responses](https://api.slack.com/interactivity/handling#message_responses)
+///
+/// Create a builder with [`SlashCommandResponse::builder()`]
+///
+/// Available fields:
+///
+/// - `.response_type(`[`ResponseType`]`)` - optional, defaults to ephemeral
+/// - `.content(`[`Into`]`<`[`MessageContent`]`>)` - required, unless
+///   deleting the original message
+/// - `.replace_original(`[`bool`]`)` - optional
+/// - `.delete_original(`[`bool`]`)` - optional
+/// - `.thread_ts(`[`Into`]`<`[`String`]`>)` - optional, only for
+///   [`ResponseUrl`](crate::response_url::ResponseUrl)
+///
+/// Finalise the builder with `.build()` to retrieve a [`SlashCommandResponse`]
+///
+/// ```
+/// # use assert_json_diff::assert_json_eq;
+/// # use serde_json::json;
+/// # use slack_lib::block_kit::blocks::*;
+/// # use slack_lib::block_kit::composition::*;
+/// # use slack_lib::slash_commands::*;
+/// let response = SlashCommandResponse::builder()
+///     .content(MessageContent::Blocks {
+///         blocks: vec![Section::builder()
+///             .text(Markdown::from("Deploying *main* to production"))
+///             .build()
+///             .into()],
+///         text: Some(String::from("Deploying...")),
+///     })
+///     .build();
+///
+/// let expected = json!({
+///   "text": "Deploying...",
+///   "blocks": [
+///     {
+///       "type": "section",
+///       "text": { "type": "mrkdwn", "text": "Deploying *main* to production" }
+///     }
+///   ]
+/// });
+///
+/// let output = serde_json::to_value(&response).unwrap();
+/// assert_json_eq!(expected, output);
+///
+/// // Deleting doesn't need any content
+/// let delete: SlashCommandResponse =
+///     serde_json::from_value(json!({ "delete_original": true })).unwrap();
+/// assert!(delete.content.is_none());
+/// ```
+#[derive(TypedBuilder, Serialize, Deserialize, Debug, Clone)]
+pub struct SlashCommandResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    pub response_type: Option<ResponseType>,
+
+    /// Only unset when deleting the original message
+    #[serde(flatten)]
+    #[builder(setter(into, strip_option), default)]
+    pub content: Option<MessageContent>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    pub replace_original: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    pub delete_original: Option<bool>,
+
+    /// Reply in a thread rather than the channel
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub thread_ts: Option<String>,
+}
@@ -0,0 +1,17 @@
+//! Helpers shared by the OAuth install flow and Sign in with Slack
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Seconds since the Unix epoch, or 0 for anything before it
+pub(crate) fn unix_time(time: SystemTime) -> i64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|since| since.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+/// `bytes` random bytes, hex encoded
+pub(crate) fn random_hex(bytes: usize) -> String {
+    let mut buffer = vec![0; bytes];
+    getrandom::getrandom(&mut buffer).expect("the OS random number generator failed");
+    hex::encode(buffer)
+}
@@ -0,0 +1,22 @@
+//! `apps.*` methods
+
+use super::Method;
+
+/// [https://api.slack.com/methods/apps.connections.open](https://api.slack.com/methods/apps.connections.open)
+///
+/// Needs an app-level token (`xapp-...`) with the `connections:write` scope,
+/// rather than a bot or user token. [`SocketModeClient`](crate::socket_mode::SocketModeClient)
+/// calls this for you.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct AppsConnectionsOpen {}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct AppsConnectionsOpenResponse {
+    /// A WebSocket URL, good for one connection
+    pub url: String,
+}
+
+impl Method for AppsConnectionsOpen {
+    const NAME: &'static str = "apps.connections.open";
+    type Response = AppsConnectionsOpenResponse;
+}
@@ -0,0 +1,186 @@
+//! `bookmarks.*` methods, and the [`Bookmark`] type they return.
+
+use super::Method;
+
+/// [https://api.slack.com/methods/bookmarks.list#examples](https://api.slack.com/methods/bookmarks.list#examples)
+///
+/// ```
+/// # use slack_lib::web_api::bookmarks::*;
+/// let bookmark: Bookmark = serde_json::from_value(serde_json::json!({
+///   "id": "Bk123RBZG8GZ",
+///   "channel_id": "C123ABC456",
+///   "title": "Runbook",
+///   "link": "https://example.com/runbook",
+///   "emoji": ":books:",
+///   "icon_url": null,
+///   "type": "link",
+///   "entity_id": null,
+///   "date_created": 1636961629,
+///   "date_updated": 0,
+///   "rank": "U",
+///   "last_updated_by_user_id": "U0362FDCNR1",
+///   "last_updated_by_team_id": "T123ABC456",
+///   "shortcut_id": null,
+///   "app_id": null
+/// }))
+/// .unwrap();
+///
+/// assert_eq!(bookmark.title, "Runbook");
+/// assert_eq!(bookmark.link.as_deref(), Some("https://example.com/runbook"));
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct Bookmark {
+    pub id: String,
+    pub channel_id: String,
+    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub link: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon_url: Option<String>,
+    /// Only `link` can be created through the API
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub bookmark_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date_created: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date_updated: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rank: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_updated_by_user_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_updated_by_team_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shortcut_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app_id: Option<String>,
+}
+
+/// [https://api.slack.com/methods/bookmarks.add](https://api.slack.com/methods/bookmarks.add)
+///
+/// ```
+/// # use assert_json_diff::assert_json_eq;
+/// # use serde_json::json;
+/// # use slack_lib::web_api::bookmarks::*;
+/// let request = BookmarksAdd::builder()
+///     .channel_id("C123ABC456")
+///     .title("Runbook")
+///     .link("https://example.com/runbook")
+///     .emoji(":books:")
+///     .build();
+///
+/// let expected = json!({
+///   "channel_id": "C123ABC456",
+///   "title": "Runbook",
+///   "type": "link",
+///   "link": "https://example.com/runbook",
+///   "emoji": ":books:"
+/// });
+///
+/// let output = serde_json::to_value(&request).unwrap();
+/// assert_json_eq!(expected, output);
+/// ```
+#[derive(TypedBuilder, Serialize)]
+pub struct BookmarksAdd {
+    #[builder(setter(into))]
+    channel_id: String,
+
+    #[builder(setter(into))]
+    title: String,
+
+    #[serde(rename = "type")]
+    #[builder(setter(skip), default = String::from("link"))]
+    bookmark_type: String,
+
+    #[builder(setter(into))]
+    link: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    emoji: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    entity_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    parent_id: Option<String>,
+}
+
+/// What `bookmarks.add` and `bookmarks.edit` respond with
+#[derive(Deserialize, Debug, Clone)]
+pub struct BookmarkResponse {
+    pub bookmark: Bookmark,
+}
+
+impl Method for BookmarksAdd {
+    const NAME: &'static str = "bookmarks.add";
+    type Response = BookmarkResponse;
+}
+
+/// [https://api.slack.com/methods/bookmarks.edit](https://api.slack.com/methods/bookmarks.edit)
+#[derive(TypedBuilder, Serialize)]
+pub struct BookmarksEdit {
+    #[builder(setter(into))]
+    bookmark_id: String,
+
+    #[builder(setter(into))]
+    channel_id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    title: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    link: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    emoji: Option<String>,
+}
+
+impl Method for BookmarksEdit {
+    const NAME: &'static str = "bookmarks.edit";
+    type Response = BookmarkResponse;
+}
+
+/// [https://api.slack.com/methods/bookmarks.remove](https://api.slack.com/methods/bookmarks.remove)
+#[derive(TypedBuilder, Serialize)]
+pub struct BookmarksRemove {
+    #[builder(setter(into))]
+    bookmark_id: String,
+
+    #[builder(setter(into))]
+    channel_id: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct BookmarksRemoveResponse {}
+
+impl Method for BookmarksRemove {
+    const NAME: &'static str = "bookmarks.remove";
+    type Response = BookmarksRemoveResponse;
+}
+
+/// [https://api.slack.com/methods/bookmarks.list](https://api.slack.com/methods/bookmarks.list)
+#[derive(TypedBuilder, Serialize)]
+pub struct BookmarksList {
+    #[builder(setter(into))]
+    channel_id: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct BookmarksListResponse {
+    pub bookmarks: Vec<Bookmark>,
+}
+
+impl Method for BookmarksList {
+    const NAME: &'static str = "bookmarks.list";
+    type Response = BookmarksListResponse;
+}
@@ -0,0 +1,74 @@
+//! Conversation (channel, DM and group DM) objects
+
+/// [https://api.slack.com/types/conversation](https://api.slack.com/types/conversation)
+///
+/// Which fields are present depends a lot on the method that returned it, and
+/// on the type of conversation, so nearly everything is optional.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct Conversation {
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name_normalized: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_channel: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_group: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_im: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_mpim: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_private: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_archived: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_general: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_shared: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_ext_shared: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_org_shared: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_member: Option<bool>,
+    /// Only set for DMs: the other user in the conversation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub topic: Option<ConversationPurpose>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub purpose: Option<ConversationPurpose>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_members: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context_team_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub shared_team_ids: Vec<String>,
+}
+
+/// Both the `topic` and `purpose` of a [`Conversation`] have this shape
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct ConversationPurpose {
+    pub value: String,
+    #[serde(default)]
+    pub creator: String,
+    #[serde(default)]
+    pub last_set: i64,
+}
+
+/// Used to filter which conversations are returned from methods like
+/// `users.conversations`
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConversationType {
+    PublicChannel,
+    PrivateChannel,
+    Mpim,
+    Im,
+}
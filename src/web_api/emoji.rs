@@ -0,0 +1,66 @@
+//! `emoji.list`
+
+use super::Method;
+use std::collections::HashMap;
+
+/// [https://api.slack.com/methods/emoji.list](https://api.slack.com/methods/emoji.list)
+#[derive(TypedBuilder, Serialize)]
+pub struct EmojiList {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    include_categories: Option<bool>,
+}
+
+/// The workspace's custom emoji.
+///
+/// Each value is either an image URL, or `alias:<name>` pointing at another
+/// emoji. Use [`EmojiListResponse::resolve`] to follow aliases.
+///
+/// ```
+/// # use slack_lib::web_api::emoji::*;
+/// let response: EmojiListResponse = serde_json::from_value(serde_json::json!({
+///   "emoji": {
+///     "shipit": "https://emoji.slack-edge.com/T9TK3CUKW/shipit/abc.png",
+///     "squirrel": "alias:shipit",
+///     "ship": "alias:squirrel"
+///   }
+/// }))
+/// .unwrap();
+///
+/// assert_eq!(
+///     response.resolve("ship"),
+///     Some("https://emoji.slack-edge.com/T9TK3CUKW/shipit/abc.png")
+/// );
+/// assert_eq!(response.resolve("nope"), None);
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct EmojiListResponse {
+    pub emoji: HashMap<String, String>,
+    #[serde(default)]
+    pub cache_ts: Option<String>,
+    /// Standard emoji grouped into categories, when `include_categories` was
+    /// set
+    #[serde(default)]
+    pub categories: Option<serde_json::Value>,
+}
+
+impl EmojiListResponse {
+    /// Follow `alias:` values until an image URL is found. Aliases that
+    /// point at standard (non-custom) emoji resolve to [`None`].
+    pub fn resolve(&self, name: &str) -> Option<&str> {
+        let mut current = self.emoji.get(name)?;
+        // Aliases can't really loop, but don't trust that
+        for _ in 0..self.emoji.len() {
+            match current.strip_prefix("alias:") {
+                Some(target) => current = self.emoji.get(target)?,
+                None => return Some(current),
+            }
+        }
+        None
+    }
+}
+
+impl Method for EmojiList {
+    const NAME: &'static str = "emoji.list";
+    type Response = EmojiListResponse;
+}
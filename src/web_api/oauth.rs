@@ -0,0 +1,134 @@
+//! `oauth.v2.access`
+//!
+//! These are called with the app's client ID and secret rather than a token,
+//! so use a [`Client`](super::Client) created with an empty token.
+
+use super::Method;
+
+/// [https://api.slack.com/methods/oauth.v2.access](https://api.slack.com/methods/oauth.v2.access)
+///
+/// Exchanges the `code` from the OAuth redirect for tokens, or refreshes a
+/// rotating token.
+///
+/// ```
+/// # use assert_json_diff::assert_json_eq;
+/// # use serde_json::json;
+/// # use slack_lib::web_api::oauth::*;
+/// let request = OauthV2Access::builder()
+///     .client_id("4b39e9-752c4")
+///     .client_secret("33fea0113f5b1")
+///     .grant(Grant::RefreshToken {
+///         refresh_token: String::from("xoxe-1-abc"),
+///     })
+///     .build();
+///
+/// let expected = json!({
+///   "client_id": "4b39e9-752c4",
+///   "client_secret": "33fea0113f5b1",
+///   "grant_type": "refresh_token",
+///   "refresh_token": "xoxe-1-abc"
+/// });
+///
+/// let output = serde_json::to_value(&request).unwrap();
+/// assert_json_eq!(expected, output);
+/// ```
+#[derive(TypedBuilder, Serialize)]
+pub struct OauthV2Access {
+    #[builder(setter(into))]
+    client_id: String,
+
+    #[builder(setter(into))]
+    client_secret: String,
+
+    #[serde(flatten)]
+    grant: Grant,
+}
+
+/// What to exchange for tokens, sent as the `grant_type` and the argument it
+/// needs
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "grant_type", rename_all = "snake_case")]
+pub enum Grant {
+    /// The `code` from the OAuth redirect
+    AuthorizationCode {
+        code: String,
+        /// Must match the `redirect_uri` used to get the `code`, if one was
+        /// used
+        #[serde(skip_serializing_if = "Option::is_none")]
+        redirect_uri: Option<String>,
+    },
+    /// The `refresh_token` that came with a rotating token
+    RefreshToken { refresh_token: String },
+}
+
+/// [https://api.slack.com/methods/oauth.v2.access#examples](https://api.slack.com/methods/oauth.v2.access#examples)
+///
+/// The top level token is a bot token (`token_type` is `bot`) when bot scopes
+/// were requested. Tokens for the installing user are in `authed_user`.
+#[derive(Deserialize, Debug, Clone)]
+pub struct OauthV2AccessResponse {
+    #[serde(default)]
+    pub access_token: Option<String>,
+    #[serde(default)]
+    pub token_type: Option<String>,
+    /// Comma separated
+    #[serde(default)]
+    pub scope: Option<String>,
+    #[serde(default)]
+    pub bot_user_id: Option<String>,
+    pub app_id: String,
+    /// Unset for org-wide installs
+    #[serde(default)]
+    pub team: Option<OauthTeam>,
+    #[serde(default)]
+    pub enterprise: Option<OauthTeam>,
+    #[serde(default)]
+    pub is_enterprise_install: bool,
+    #[serde(default)]
+    pub authed_user: Option<AuthedUser>,
+    #[serde(default)]
+    pub incoming_webhook: Option<IncomingWebhookInfo>,
+    /// Only set when token rotation is turned on
+    #[serde(default)]
+    pub refresh_token: Option<String>,
+    /// Seconds until `access_token` expires, when token rotation is on
+    #[serde(default)]
+    pub expires_in: Option<i64>,
+}
+
+/// Used for both `team` and `enterprise`
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct OauthTeam {
+    pub id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct AuthedUser {
+    pub id: String,
+    #[serde(default)]
+    pub scope: Option<String>,
+    #[serde(default)]
+    pub access_token: Option<String>,
+    #[serde(default)]
+    pub token_type: Option<String>,
+    #[serde(default)]
+    pub refresh_token: Option<String>,
+    #[serde(default)]
+    pub expires_in: Option<i64>,
+}
+
+/// Set when the `incoming-webhook` scope was requested
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct IncomingWebhookInfo {
+    pub channel: String,
+    pub channel_id: String,
+    pub configuration_url: String,
+    pub url: String,
+}
+
+impl Method for OauthV2Access {
+    const NAME: &'static str = "oauth.v2.access";
+    type Response = OauthV2AccessResponse;
+}
@@ -0,0 +1,113 @@
+//! `openid.connect.*`, for Sign in with Slack
+//!
+//! `openid.connect.token` is called with the app's client ID and secret, like
+//! `oauth.v2.access`, and `openid.connect.userInfo` with the user token it
+//! returns.
+
+use super::oauth::Grant;
+use super::Method;
+
+/// [https://api.slack.com/methods/openid.connect.token](https://api.slack.com/methods/openid.connect.token)
+///
+/// ```
+/// # use assert_json_diff::assert_json_eq;
+/// # use serde_json::json;
+/// # use slack_lib::web_api::openid::*;
+/// # use slack_lib::web_api::oauth::Grant;
+/// let request = OpenidConnectToken::builder()
+///     .client_id("4b39e9-752c4")
+///     .client_secret("33fea0113f5b1")
+///     .grant(Grant::AuthorizationCode {
+///         code: String::from("1234.5678"),
+///         redirect_uri: Some(String::from("https://example.com/slack/sign_in")),
+///     })
+///     .build();
+///
+/// let expected = json!({
+///   "client_id": "4b39e9-752c4",
+///   "client_secret": "33fea0113f5b1",
+///   "grant_type": "authorization_code",
+///   "code": "1234.5678",
+///   "redirect_uri": "https://example.com/slack/sign_in"
+/// });
+///
+/// let output = serde_json::to_value(&request).unwrap();
+/// assert_json_eq!(expected, output);
+/// ```
+#[derive(TypedBuilder, Serialize)]
+pub struct OpenidConnectToken {
+    #[builder(setter(into))]
+    client_id: String,
+
+    #[builder(setter(into))]
+    client_secret: String,
+
+    /// The same grants as `oauth.v2.access`
+    #[serde(flatten)]
+    grant: Grant,
+}
+
+/// [https://api.slack.com/methods/openid.connect.token#examples](https://api.slack.com/methods/openid.connect.token#examples)
+#[derive(Deserialize, Debug, Clone)]
+pub struct OpenidConnectTokenResponse {
+    /// A user token (`xoxp-...`), for `openid.connect.userInfo`
+    pub access_token: String,
+    #[serde(default)]
+    pub token_type: Option<String>,
+    /// A JWT. Check it with
+    /// [`IdTokenValidator`](crate::openid::IdTokenValidator) before trusting
+    /// its claims.
+    pub id_token: String,
+    /// Only set when token rotation is turned on
+    #[serde(default)]
+    pub refresh_token: Option<String>,
+    #[serde(default)]
+    pub expires_in: Option<i64>,
+}
+
+impl Method for OpenidConnectToken {
+    const NAME: &'static str = "openid.connect.token";
+    type Response = OpenidConnectTokenResponse;
+}
+
+/// [https://api.slack.com/methods/openid.connect.userInfo](https://api.slack.com/methods/openid.connect.userInfo)
+#[derive(Serialize, Default)]
+pub struct OpenidConnectUserInfo {}
+
+/// [https://api.slack.com/methods/openid.connect.userInfo#examples](https://api.slack.com/methods/openid.connect.userInfo#examples)
+///
+/// The `email` fields are only set with the `email` scope, and the name and
+/// picture fields with the `profile` scope.
+#[derive(Deserialize, Debug, Clone)]
+pub struct OpenidConnectUserInfoResponse {
+    pub sub: String,
+    #[serde(rename = "https://slack.com/user_id")]
+    pub user_id: String,
+    #[serde(rename = "https://slack.com/team_id")]
+    pub team_id: String,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub email_verified: Option<bool>,
+    #[serde(default)]
+    pub date_email_verified: Option<i64>,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub picture: Option<String>,
+    #[serde(default)]
+    pub given_name: Option<String>,
+    #[serde(default)]
+    pub family_name: Option<String>,
+    #[serde(default)]
+    pub locale: Option<String>,
+    #[serde(default, rename = "https://slack.com/team_name")]
+    pub team_name: Option<String>,
+    #[serde(default, rename = "https://slack.com/team_domain")]
+    pub team_domain: Option<String>,
+}
+
+impl Method for OpenidConnectUserInfo {
+    const NAME: &'static str = "openid.connect.userInfo";
+    type Response = OpenidConnectUserInfoResponse;
+}
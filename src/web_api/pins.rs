@@ -0,0 +1,106 @@
+//! `pins.*` methods
+
+use super::reactions::Item;
+use super::Method;
+
+/// An [`Item`] pinned to a channel, along with who pinned it and when
+#[derive(Deserialize, Debug, Clone)]
+pub struct PinnedItem {
+    #[serde(default)]
+    pub created: Option<i64>,
+    #[serde(default)]
+    pub created_by: Option<String>,
+    #[serde(flatten)]
+    pub item: Item,
+}
+
+/// [https://api.slack.com/methods/pins.add](https://api.slack.com/methods/pins.add)
+///
+/// ```
+/// # use assert_json_diff::assert_json_eq;
+/// # use serde_json::json;
+/// # use slack_lib::web_api::pins::*;
+/// let request = PinsAdd::builder()
+///     .channel("C1234567890")
+///     .timestamp("1234567890.123456")
+///     .build();
+///
+/// let expected = json!({
+///   "channel": "C1234567890",
+///   "timestamp": "1234567890.123456"
+/// });
+///
+/// let output = serde_json::to_value(&request).unwrap();
+/// assert_json_eq!(expected, output);
+/// ```
+#[derive(TypedBuilder, Serialize)]
+pub struct PinsAdd {
+    #[builder(setter(into))]
+    channel: String,
+
+    #[builder(setter(into))]
+    timestamp: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct PinsAddResponse {}
+
+impl Method for PinsAdd {
+    const NAME: &'static str = "pins.add";
+    type Response = PinsAddResponse;
+}
+
+/// [https://api.slack.com/methods/pins.remove](https://api.slack.com/methods/pins.remove)
+#[derive(TypedBuilder, Serialize)]
+pub struct PinsRemove {
+    #[builder(setter(into))]
+    channel: String,
+
+    #[builder(setter(into))]
+    timestamp: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct PinsRemoveResponse {}
+
+impl Method for PinsRemove {
+    const NAME: &'static str = "pins.remove";
+    type Response = PinsRemoveResponse;
+}
+
+/// [https://api.slack.com/methods/pins.list](https://api.slack.com/methods/pins.list)
+///
+/// ```
+/// # use slack_lib::web_api::pins::*;
+/// # use slack_lib::web_api::reactions::Item;
+/// let response: PinsListResponse = serde_json::from_value(serde_json::json!({
+///   "items": [
+///     {
+///       "type": "message",
+///       "channel": "C2U86NC6H",
+///       "created": 1508881078,
+///       "created_by": "U2U85N1RZ",
+///       "message": { "type": "message", "text": "Daily summary", "ts": "1508284197.000015" }
+///     }
+///   ]
+/// }))
+/// .unwrap();
+///
+/// assert_eq!(response.items[0].created_by.as_deref(), Some("U2U85N1RZ"));
+/// assert!(matches!(response.items[0].item, Item::Message { .. }));
+/// ```
+#[derive(TypedBuilder, Serialize)]
+pub struct PinsList {
+    #[builder(setter(into))]
+    channel: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct PinsListResponse {
+    pub items: Vec<PinnedItem>,
+}
+
+impl Method for PinsList {
+    const NAME: &'static str = "pins.list";
+    type Response = PinsListResponse;
+}
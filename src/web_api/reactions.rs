@@ -0,0 +1,217 @@
+//! `reactions.*` methods, and the [`Reaction`] type they return.
+
+use super::files::File;
+use super::{Method, ResponseMetadata};
+use crate::events::message::Message;
+
+/// [https://api.slack.com/methods/reactions.get#response](https://api.slack.com/methods/reactions.get#response)
+///
+/// `name` is the emoji name without colons, e.g. `thumbsup`. Skin tones show
+/// up as a suffix, e.g. `thumbsup::skin-tone-2`.
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+pub struct Reaction {
+    pub name: String,
+    #[serde(default)]
+    pub count: u32,
+    /// May not be complete for popular reactions. Compare its length to
+    /// `count`.
+    #[serde(default)]
+    pub users: Vec<String>,
+}
+
+/// Something that can be reacted to (or pinned, or starred)
+#[derive(Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Item {
+    Message {
+        channel: String,
+        message: Box<Message>,
+    },
+    File {
+        file: File,
+    },
+    FileComment {
+        file: File,
+        comment: serde_json::Value,
+    },
+}
+
+impl Item {
+    /// The reactions on the item, whatever kind it is
+    pub fn reactions(&self) -> Vec<Reaction> {
+        match self {
+            Item::Message { message, .. } => message.reactions.clone(),
+            Item::File { file } => file.reactions.clone(),
+            Item::FileComment { comment, .. } => comment
+                .get("reactions")
+                .cloned()
+                .and_then(|reactions| serde_json::from_value(reactions).ok())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Which item a call is about (to react to, pin or star it): a message, a
+/// file, or a file comment
+#[derive(Serialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum ItemRef {
+    Message { channel: String, timestamp: String },
+    File { file: String },
+    FileComment { file_comment: String },
+}
+
+impl ItemRef {
+    pub fn message(channel: impl Into<String>, timestamp: impl Into<String>) -> Self {
+        ItemRef::Message {
+            channel: channel.into(),
+            timestamp: timestamp.into(),
+        }
+    }
+}
+
+/// [https://api.slack.com/methods/reactions.add](https://api.slack.com/methods/reactions.add)
+///
+/// ```
+/// # use assert_json_diff::assert_json_eq;
+/// # use serde_json::json;
+/// # use slack_lib::web_api::reactions::*;
+/// let request = ReactionsAdd::builder()
+///     .channel("C1234567890")
+///     .timestamp("1234567890.123456")
+///     .name("eyes")
+///     .build();
+///
+/// let expected = json!({
+///   "channel": "C1234567890",
+///   "timestamp": "1234567890.123456",
+///   "name": "eyes"
+/// });
+///
+/// let output = serde_json::to_value(&request).unwrap();
+/// assert_json_eq!(expected, output);
+/// ```
+#[derive(TypedBuilder, Serialize)]
+pub struct ReactionsAdd {
+    #[builder(setter(into))]
+    channel: String,
+
+    #[builder(setter(into))]
+    timestamp: String,
+
+    /// Emoji name without colons
+    #[builder(setter(into))]
+    name: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ReactionsAddResponse {}
+
+impl Method for ReactionsAdd {
+    const NAME: &'static str = "reactions.add";
+    type Response = ReactionsAddResponse;
+}
+
+/// [https://api.slack.com/methods/reactions.remove](https://api.slack.com/methods/reactions.remove)
+#[derive(TypedBuilder, Serialize)]
+pub struct ReactionsRemove {
+    /// Emoji name without colons
+    #[builder(setter(into))]
+    name: String,
+
+    #[serde(flatten)]
+    item: ItemRef,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ReactionsRemoveResponse {}
+
+impl Method for ReactionsRemove {
+    const NAME: &'static str = "reactions.remove";
+    type Response = ReactionsRemoveResponse;
+}
+
+/// [https://api.slack.com/methods/reactions.get](https://api.slack.com/methods/reactions.get)
+///
+/// ```
+/// # use slack_lib::web_api::reactions::*;
+/// let response: ReactionsGetResponse = serde_json::from_value(serde_json::json!({
+///   "type": "message",
+///   "channel": "C123ABC456",
+///   "message": {
+///     "type": "message",
+///     "text": "Hi there!",
+///     "user": "W123456",
+///     "ts": "1648602352.215969",
+///     "reactions": [
+///       { "name": "grinning", "users": ["W222222"], "count": 1 },
+///       { "name": "question", "users": ["W333333"], "count": 1 }
+///     ]
+///   }
+/// }))
+/// .unwrap();
+///
+/// let reactions = response.item.reactions();
+/// assert_eq!(reactions[0].name, "grinning");
+/// assert_eq!(reactions.len(), 2);
+/// ```
+#[derive(TypedBuilder, Serialize)]
+pub struct ReactionsGet {
+    #[serde(flatten)]
+    item: ItemRef,
+
+    /// Return the complete list of `users` for each reaction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    full: Option<bool>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ReactionsGetResponse {
+    #[serde(flatten)]
+    pub item: Item,
+}
+
+impl Method for ReactionsGet {
+    const NAME: &'static str = "reactions.get";
+    type Response = ReactionsGetResponse;
+}
+
+/// [https://api.slack.com/methods/reactions.list](https://api.slack.com/methods/reactions.list)
+///
+/// Lists the items a user has reacted to.
+#[derive(TypedBuilder, Serialize)]
+pub struct ReactionsList {
+    /// Defaults to the user who owns the token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    user: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    full: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    cursor: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    limit: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    team_id: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ReactionsListResponse {
+    pub items: Vec<Item>,
+    #[serde(default)]
+    pub response_metadata: ResponseMetadata,
+}
+
+impl Method for ReactionsList {
+    const NAME: &'static str = "reactions.list";
+    type Response = ReactionsListResponse;
+}
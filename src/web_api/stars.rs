@@ -0,0 +1,92 @@
+//! `stars.*` methods.
+//!
+//! Slack has replaced stars with "saved items" in its own clients, but these
+//! still work for the items a user starred before that.
+
+use super::reactions::{Item, ItemRef};
+use super::{Method, ResponseMetadata};
+
+/// An [`Item`] a user starred, along with when they starred it
+#[derive(Deserialize, Debug, Clone)]
+pub struct StarredItem {
+    #[serde(default)]
+    pub date_create: Option<i64>,
+    #[serde(flatten)]
+    pub item: Item,
+}
+
+/// [https://api.slack.com/methods/stars.add](https://api.slack.com/methods/stars.add)
+///
+/// ```
+/// # use assert_json_diff::assert_json_eq;
+/// # use serde_json::json;
+/// # use slack_lib::web_api::reactions::ItemRef;
+/// # use slack_lib::web_api::stars::*;
+/// let request = StarsAdd::builder()
+///     .item(ItemRef::message("C1234567890", "1234567890.123456"))
+///     .build();
+///
+/// let expected = json!({
+///   "channel": "C1234567890",
+///   "timestamp": "1234567890.123456"
+/// });
+///
+/// let output = serde_json::to_value(&request).unwrap();
+/// assert_json_eq!(expected, output);
+/// ```
+#[derive(TypedBuilder, Serialize)]
+pub struct StarsAdd {
+    #[serde(flatten)]
+    item: ItemRef,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct StarsAddResponse {}
+
+impl Method for StarsAdd {
+    const NAME: &'static str = "stars.add";
+    type Response = StarsAddResponse;
+}
+
+/// [https://api.slack.com/methods/stars.remove](https://api.slack.com/methods/stars.remove)
+#[derive(TypedBuilder, Serialize)]
+pub struct StarsRemove {
+    #[serde(flatten)]
+    item: ItemRef,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct StarsRemoveResponse {}
+
+impl Method for StarsRemove {
+    const NAME: &'static str = "stars.remove";
+    type Response = StarsRemoveResponse;
+}
+
+/// [https://api.slack.com/methods/stars.list](https://api.slack.com/methods/stars.list)
+#[derive(TypedBuilder, Serialize)]
+pub struct StarsList {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    cursor: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    limit: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    team_id: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct StarsListResponse {
+    pub items: Vec<StarredItem>,
+    #[serde(default)]
+    pub response_metadata: ResponseMetadata,
+}
+
+impl Method for StarsList {
+    const NAME: &'static str = "stars.list";
+    type Response = StarsListResponse;
+}
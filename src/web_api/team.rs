@@ -0,0 +1,231 @@
+//! `team.*` and `team.profile.*` methods
+
+use super::{Method, Paging, ResponseMetadata};
+use std::collections::HashMap;
+
+/// [https://api.slack.com/methods/team.info#examples](https://api.slack.com/methods/team.info#examples)
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct Team {
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub domain: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_domain: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// `image_34`, `image_44`, ... `image_original`, `image_default`
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub icon: HashMap<String, serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enterprise_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enterprise_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enterprise_domain: Option<String>,
+}
+
+/// [https://api.slack.com/methods/team.info](https://api.slack.com/methods/team.info)
+///
+/// Leave both arguments out to get the team the token belongs to.
+#[derive(TypedBuilder, Serialize)]
+pub struct TeamInfo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    team: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    domain: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct TeamInfoResponse {
+    pub team: Team,
+}
+
+impl Method for TeamInfo {
+    const NAME: &'static str = "team.info";
+    type Response = TeamInfoResponse;
+}
+
+/// A user's logins from one IP address and user agent
+#[derive(Deserialize, Debug, Clone)]
+pub struct Login {
+    pub user_id: String,
+    #[serde(default)]
+    pub username: Option<String>,
+    pub date_first: i64,
+    pub date_last: i64,
+    #[serde(default)]
+    pub count: i64,
+    #[serde(default)]
+    pub ip: Option<String>,
+    #[serde(default)]
+    pub user_agent: Option<String>,
+    #[serde(default)]
+    pub isp: Option<String>,
+    #[serde(default)]
+    pub country: Option<String>,
+    #[serde(default)]
+    pub region: Option<String>,
+}
+
+/// [https://api.slack.com/methods/team.accessLogs](https://api.slack.com/methods/team.accessLogs)
+///
+/// Requires a paid plan, and the `admin` scope.
+#[derive(TypedBuilder, Serialize)]
+pub struct TeamAccessLogs {
+    /// Only return logins before this Unix timestamp
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    before: Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    count: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    page: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    cursor: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    limit: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    team_id: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct TeamAccessLogsResponse {
+    pub logins: Vec<Login>,
+    #[serde(default)]
+    pub paging: Paging,
+    #[serde(default)]
+    pub response_metadata: ResponseMetadata,
+}
+
+impl Method for TeamAccessLogs {
+    const NAME: &'static str = "team.accessLogs";
+    type Response = TeamAccessLogsResponse;
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BillableInfo {
+    pub billing_active: bool,
+}
+
+/// [https://api.slack.com/methods/team.billableInfo](https://api.slack.com/methods/team.billableInfo)
+///
+/// ```
+/// # use slack_lib::web_api::team::*;
+/// let response: TeamBillableInfoResponse = serde_json::from_value(serde_json::json!({
+///   "billable_info": {
+///     "U0632EWRW": { "billing_active": false },
+///     "U02UCPE1R": { "billing_active": true }
+///   }
+/// }))
+/// .unwrap();
+///
+/// assert!(response.billable_info["U02UCPE1R"].billing_active);
+/// ```
+#[derive(TypedBuilder, Serialize)]
+pub struct TeamBillableInfo {
+    /// Leave out to get everyone on the team
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    user: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    cursor: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    limit: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    team_id: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct TeamBillableInfoResponse {
+    /// Keyed by user ID
+    pub billable_info: HashMap<String, BillableInfo>,
+    #[serde(default)]
+    pub response_metadata: ResponseMetadata,
+}
+
+impl Method for TeamBillableInfo {
+    const NAME: &'static str = "team.billableInfo";
+    type Response = TeamBillableInfoResponse;
+}
+
+/// The definition of a custom profile field. The values themselves live in
+/// [`UserProfile::fields`](super::users::UserProfile::fields), keyed by `id`.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct TeamProfileField {
+    pub id: String,
+    #[serde(default)]
+    pub ordering: i64,
+    pub label: String,
+    #[serde(default)]
+    pub hint: String,
+    /// `text`, `date`, `link`, `options_list` or `user`
+    #[serde(rename = "type")]
+    pub field_type: String,
+    /// Only set for `options_list` fields
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub possible_values: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options: Option<serde_json::Value>,
+    #[serde(default)]
+    pub is_hidden: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub section_id: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct TeamProfile {
+    pub fields: Vec<TeamProfileField>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sections: Vec<serde_json::Value>,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ProfileFieldVisibility {
+    All,
+    Visible,
+    Hidden,
+}
+
+/// [https://api.slack.com/methods/team.profile.get](https://api.slack.com/methods/team.profile.get)
+#[derive(TypedBuilder, Serialize)]
+pub struct TeamProfileGet {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    visibility: Option<ProfileFieldVisibility>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    team_id: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct TeamProfileGetResponse {
+    pub profile: TeamProfile,
+}
+
+impl Method for TeamProfileGet {
+    const NAME: &'static str = "team.profile.get";
+    type Response = TeamProfileGetResponse;
+}
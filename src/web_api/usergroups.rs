@@ -0,0 +1,337 @@
+//! `usergroups.*` and `usergroups.users.*` methods, along with the
+//! [`Usergroup`] type they return.
+
+use super::Method;
+
+/// [https://api.slack.com/types/usergroup](https://api.slack.com/types/usergroup)
+///
+/// Mention a group in [`Markdown`](crate::block_kit::composition::Markdown)
+/// text with [`Usergroup::mention`]:
+///
+/// ```
+/// # use slack_lib::block_kit::composition::Markdown;
+/// # use slack_lib::web_api::usergroups::*;
+/// let group: Usergroup = serde_json::from_value(serde_json::json!({
+///   "id": "S0614TZR7",
+///   "team_id": "T060RNRCH",
+///   "is_usergroup": true,
+///   "name": "Team Admins",
+///   "description": "A group of all Administrators on your team.",
+///   "handle": "admins",
+///   "is_external": false,
+///   "date_create": 1446598059,
+///   "date_update": 1446670362,
+///   "date_delete": 0,
+///   "auto_type": "admin",
+///   "created_by": "USLACKBOT",
+///   "updated_by": "U060RNRCZ",
+///   "deleted_by": null,
+///   "prefs": { "channels": [], "groups": [] },
+///   "users": ["U060RNRCZ", "U060ULRC0"],
+///   "user_count": 2
+/// }))
+/// .unwrap();
+///
+/// assert_eq!(group.mention(), "<!subteam^S0614TZR7>");
+///
+/// let text = Markdown::from(format!("{} the deploy is done", group.mention()).as_str());
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct Usergroup {
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+    #[serde(default)]
+    pub is_usergroup: bool,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub handle: String,
+    #[serde(default)]
+    pub is_external: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date_create: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date_update: Option<i64>,
+    /// Non-zero when the group is disabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date_delete: Option<i64>,
+    /// `admin`, `owner` or unset for regular groups
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_by: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_by: Option<String>,
+    #[serde(default)]
+    pub prefs: UsergroupPrefs,
+    /// Only included when `include_users` was requested
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub users: Vec<String>,
+    /// Only included when `include_count` was requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_count: Option<i64>,
+}
+
+impl Usergroup {
+    /// The mrkdwn that mentions (and notifies) everyone in the group
+    pub fn mention(&self) -> String {
+        format!("<!subteam^{}>", self.id)
+    }
+
+    pub fn is_disabled(&self) -> bool {
+        self.date_delete.unwrap_or(0) != 0
+    }
+}
+
+/// The channels a group's members are added to by default
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct UsergroupPrefs {
+    #[serde(default)]
+    pub channels: Vec<String>,
+    #[serde(default)]
+    pub groups: Vec<String>,
+}
+
+/// What all of the single-group `usergroups.*` methods respond with
+#[derive(Deserialize, Debug, Clone)]
+pub struct UsergroupResponse {
+    pub usergroup: Usergroup,
+}
+
+/// [https://api.slack.com/methods/usergroups.create](https://api.slack.com/methods/usergroups.create)
+///
+/// ```
+/// # use assert_json_diff::assert_json_eq;
+/// # use serde_json::json;
+/// # use slack_lib::web_api::usergroups::*;
+/// let request = UsergroupsCreate::builder()
+///     .name("On call")
+///     .handle("oncall")
+///     .channels(vec![String::from("C1234567890"), String::from("C2345678901")])
+///     .build();
+///
+/// let expected = json!({
+///   "name": "On call",
+///   "handle": "oncall",
+///   "channels": "C1234567890,C2345678901"
+/// });
+///
+/// let output = serde_json::to_value(&request).unwrap();
+/// assert_json_eq!(expected, output);
+/// ```
+#[derive(TypedBuilder, Serialize)]
+pub struct UsergroupsCreate {
+    #[builder(setter(into))]
+    name: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    handle: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    description: Option<String>,
+
+    /// Default channels for the group's members
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "super::serialize_comma_separated"
+    )]
+    #[builder(setter(strip_option), default)]
+    channels: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    include_count: Option<bool>,
+
+    /// Required for org-wide apps
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    team_id: Option<String>,
+}
+
+impl Method for UsergroupsCreate {
+    const NAME: &'static str = "usergroups.create";
+    type Response = UsergroupResponse;
+}
+
+/// [https://api.slack.com/methods/usergroups.update](https://api.slack.com/methods/usergroups.update)
+#[derive(TypedBuilder, Serialize)]
+pub struct UsergroupsUpdate {
+    #[builder(setter(into))]
+    usergroup: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    handle: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    description: Option<String>,
+
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "super::serialize_comma_separated"
+    )]
+    #[builder(setter(strip_option), default)]
+    channels: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    include_count: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    team_id: Option<String>,
+}
+
+impl Method for UsergroupsUpdate {
+    const NAME: &'static str = "usergroups.update";
+    type Response = UsergroupResponse;
+}
+
+/// [https://api.slack.com/methods/usergroups.list](https://api.slack.com/methods/usergroups.list)
+#[derive(TypedBuilder, Serialize)]
+pub struct UsergroupsList {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    include_count: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    include_disabled: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    include_users: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    team_id: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct UsergroupsListResponse {
+    pub usergroups: Vec<Usergroup>,
+}
+
+impl Method for UsergroupsList {
+    const NAME: &'static str = "usergroups.list";
+    type Response = UsergroupsListResponse;
+}
+
+/// [https://api.slack.com/methods/usergroups.enable](https://api.slack.com/methods/usergroups.enable)
+#[derive(TypedBuilder, Serialize)]
+pub struct UsergroupsEnable {
+    #[builder(setter(into))]
+    usergroup: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    include_count: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    team_id: Option<String>,
+}
+
+impl Method for UsergroupsEnable {
+    const NAME: &'static str = "usergroups.enable";
+    type Response = UsergroupResponse;
+}
+
+/// [https://api.slack.com/methods/usergroups.disable](https://api.slack.com/methods/usergroups.disable)
+#[derive(TypedBuilder, Serialize)]
+pub struct UsergroupsDisable {
+    #[builder(setter(into))]
+    usergroup: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    include_count: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    team_id: Option<String>,
+}
+
+impl Method for UsergroupsDisable {
+    const NAME: &'static str = "usergroups.disable";
+    type Response = UsergroupResponse;
+}
+
+/// [https://api.slack.com/methods/usergroups.users.list](https://api.slack.com/methods/usergroups.users.list)
+#[derive(TypedBuilder, Serialize)]
+pub struct UsergroupsUsersList {
+    #[builder(setter(into))]
+    usergroup: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    include_disabled: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    team_id: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct UsergroupsUsersListResponse {
+    pub users: Vec<String>,
+}
+
+impl Method for UsergroupsUsersList {
+    const NAME: &'static str = "usergroups.users.list";
+    type Response = UsergroupsUsersListResponse;
+}
+
+/// [https://api.slack.com/methods/usergroups.users.update](https://api.slack.com/methods/usergroups.users.update)
+///
+/// Replaces the whole membership of the group with `users`, e.g. when an
+/// on-call rotation changes hands.
+///
+/// ```
+/// # use assert_json_diff::assert_json_eq;
+/// # use serde_json::json;
+/// # use slack_lib::web_api::usergroups::*;
+/// let request = UsergroupsUsersUpdate::builder()
+///     .usergroup("S0604QSJC")
+///     .users(vec![String::from("U060R4BJ4"), String::from("U060RNRCZ")])
+///     .build();
+///
+/// let expected = json!({
+///   "usergroup": "S0604QSJC",
+///   "users": "U060R4BJ4,U060RNRCZ"
+/// });
+///
+/// let output = serde_json::to_value(&request).unwrap();
+/// assert_json_eq!(expected, output);
+/// ```
+#[derive(TypedBuilder, Serialize)]
+pub struct UsergroupsUsersUpdate {
+    #[builder(setter(into))]
+    usergroup: String,
+
+    #[serde(serialize_with = "super::serialize_comma_joined")]
+    users: Vec<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    include_count: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    team_id: Option<String>,
+}
+
+impl Method for UsergroupsUsersUpdate {
+    const NAME: &'static str = "usergroups.users.update";
+    type Response = UsergroupResponse;
+}
@@ -0,0 +1,538 @@
+//! `users.*` and `users.profile.*` methods, along with the [`User`] and
+//! [`UserProfile`] types they return.
+
+use super::conversations::{Conversation, ConversationType};
+use super::{Method, ResponseMetadata};
+use std::collections::HashMap;
+
+/// [https://api.slack.com/types/user](https://api.slack.com/types/user)
+///
+/// ```
+/// # use slack_lib::web_api::users::*;
+/// let user: User = serde_json::from_value(serde_json::json!({
+///   "id": "W012A3CDE",
+///   "team_id": "T012AB3C4",
+///   "name": "spengler",
+///   "deleted": false,
+///   "real_name": "Egon Spengler",
+///   "tz": "America/Los_Angeles",
+///   "tz_offset": -25200,
+///   "profile": {
+///     "real_name": "Egon Spengler",
+///     "display_name": "spengler",
+///     "email": "spengler@ghostbusters.example.com",
+///     "fields": {
+///       "Xf06054BBB": { "value": "Ghostbusters", "alt": "" }
+///     }
+///   },
+///   "is_admin": true,
+///   "is_bot": false,
+///   "updated": 1502138686,
+///   "enterprise_user": {
+///     "id": "U123ABC456",
+///     "enterprise_id": "E1234ABCD",
+///     "enterprise_name": "Ghostbusters Inc",
+///     "is_admin": false,
+///     "is_owner": false,
+///     "teams": ["T012AB3C4"]
+///   }
+/// }))
+/// .unwrap();
+///
+/// assert_eq!(
+///     user.profile.email.as_deref(),
+///     Some("spengler@ghostbusters.example.com")
+/// );
+/// assert_eq!(user.profile.fields["Xf06054BBB"].value, "Ghostbusters");
+/// assert_eq!(user.enterprise_user.unwrap().enterprise_id, "E1234ABCD");
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct User {
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub deleted: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub real_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tz: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tz_label: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tz_offset: Option<i64>,
+    #[serde(default)]
+    pub profile: UserProfile,
+    #[serde(default)]
+    pub is_admin: bool,
+    #[serde(default)]
+    pub is_owner: bool,
+    #[serde(default)]
+    pub is_primary_owner: bool,
+    #[serde(default)]
+    pub is_restricted: bool,
+    #[serde(default)]
+    pub is_ultra_restricted: bool,
+    #[serde(default)]
+    pub is_bot: bool,
+    #[serde(default)]
+    pub is_app_user: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_stranger: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_2fa: Option<bool>,
+    /// Only included when `include_locale` was requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    /// Only set for users on an Enterprise Grid org
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enterprise_user: Option<EnterpriseUser>,
+}
+
+/// [https://api.slack.com/types/user#profile](https://api.slack.com/types/user#profile)
+///
+/// This is used both for reading a profile, and for `users.profile.set`. When
+/// setting, only include the fields you want to change:
+///
+/// ```
+/// # use assert_json_diff::assert_json_eq;
+/// # use serde_json::json;
+/// # use slack_lib::web_api::users::*;
+/// let profile = UserProfile {
+///     status_text: Some(String::from("riding a train")),
+///     status_emoji: Some(String::from(":mountain_railway:")),
+///     fields: vec![(
+///         String::from("Xf06054BBB"),
+///         ProfileField::from("On call"),
+///     )]
+///     .into_iter()
+///     .collect(),
+///     ..Default::default()
+/// };
+///
+/// let expected = json!({
+///   "status_text": "riding a train",
+///   "status_emoji": ":mountain_railway:",
+///   "fields": {
+///     "Xf06054BBB": { "value": "On call" }
+///   }
+/// });
+///
+/// let output = serde_json::to_value(&profile).unwrap();
+/// assert_json_eq!(expected, output);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct UserProfile {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skype: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub real_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub real_name_normalized: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name_normalized: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    /// Requires the `users:read.email` scope
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pronouns: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_text: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_emoji: Option<String>,
+    /// Unix timestamp of when the status clears, `0` for never
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_expiration: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image_original: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image_24: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image_32: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image_48: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image_72: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image_192: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image_512: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<String>,
+    /// Set on bot users' profiles
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bot_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_app_id: Option<String>,
+    /// Custom profile fields, keyed by field ID (e.g. `Xf06054BBB`). Slack
+    /// sends `null` rather than `{}` when there are none.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_null_default",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    pub fields: HashMap<String, ProfileField>,
+}
+
+/// A custom profile field value.
+///
+/// `label` is only populated by `users.profile.get` with `include_labels`.
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+pub struct ProfileField {
+    pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alt: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+impl From<&str> for ProfileField {
+    fn from(value: &str) -> Self {
+        Self {
+            value: String::from(value),
+            ..Default::default()
+        }
+    }
+}
+
+/// The `enterprise_user` portion of a [`User`] on an Enterprise Grid org
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct EnterpriseUser {
+    pub id: String,
+    pub enterprise_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enterprise_name: Option<String>,
+    #[serde(default)]
+    pub is_admin: bool,
+    #[serde(default)]
+    pub is_owner: bool,
+    #[serde(default)]
+    pub teams: Vec<String>,
+}
+
+fn deserialize_null_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Default + serde::Deserialize<'de>,
+{
+    let value: Option<T> = serde::Deserialize::deserialize(deserializer)?;
+    Ok(value.unwrap_or_default())
+}
+
+/// [https://api.slack.com/methods/users.info](https://api.slack.com/methods/users.info)
+///
+/// ```
+/// # use assert_json_diff::assert_json_eq;
+/// # use serde_json::json;
+/// # use slack_lib::web_api::users::*;
+/// let request = UsersInfo::builder()
+///     .user("W012A3CDE")
+///     .include_locale(true)
+///     .build();
+///
+/// let expected = json!({
+///   "user": "W012A3CDE",
+///   "include_locale": true
+/// });
+///
+/// let output = serde_json::to_value(&request).unwrap();
+/// assert_json_eq!(expected, output);
+/// ```
+#[derive(TypedBuilder, Serialize)]
+pub struct UsersInfo {
+    #[builder(setter(into))]
+    user: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    include_locale: Option<bool>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct UsersInfoResponse {
+    pub user: User,
+}
+
+impl Method for UsersInfo {
+    const NAME: &'static str = "users.info";
+    type Response = UsersInfoResponse;
+}
+
+/// [https://api.slack.com/methods/users.lookupByEmail](https://api.slack.com/methods/users.lookupByEmail)
+///
+/// Fails with `users_not_found` if nobody on the workspace has that email.
+#[derive(TypedBuilder, Serialize)]
+pub struct UsersLookupByEmail {
+    #[builder(setter(into))]
+    email: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct UsersLookupByEmailResponse {
+    pub user: User,
+}
+
+impl Method for UsersLookupByEmail {
+    const NAME: &'static str = "users.lookupByEmail";
+    type Response = UsersLookupByEmailResponse;
+}
+
+/// [https://api.slack.com/methods/users.list](https://api.slack.com/methods/users.list)
+///
+/// This is paginated. Pass `response_metadata.next_cursor()` from each
+/// response back in as `cursor` until it's [`None`].
+#[derive(TypedBuilder, Serialize)]
+pub struct UsersList {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    cursor: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    limit: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    include_locale: Option<bool>,
+
+    /// Required for org-wide apps
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    team_id: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct UsersListResponse {
+    pub members: Vec<User>,
+    #[serde(default)]
+    pub cache_ts: Option<i64>,
+    #[serde(default)]
+    pub response_metadata: ResponseMetadata,
+}
+
+impl Method for UsersList {
+    const NAME: &'static str = "users.list";
+    type Response = UsersListResponse;
+}
+
+/// [https://api.slack.com/methods/users.conversations](https://api.slack.com/methods/users.conversations)
+///
+/// `types` is sent as a comma separated list:
+///
+/// ```
+/// # use assert_json_diff::assert_json_eq;
+/// # use serde_json::json;
+/// # use slack_lib::web_api::conversations::ConversationType;
+/// # use slack_lib::web_api::users::*;
+/// let request = UsersConversations::builder()
+///     .user("W012A3CDE")
+///     .types(vec![ConversationType::PublicChannel, ConversationType::Im])
+///     .exclude_archived(true)
+///     .build();
+///
+/// let expected = json!({
+///   "user": "W012A3CDE",
+///   "types": "public_channel,im",
+///   "exclude_archived": true
+/// });
+///
+/// let output = serde_json::to_value(&request).unwrap();
+/// assert_json_eq!(expected, output);
+/// ```
+#[derive(TypedBuilder, Serialize)]
+pub struct UsersConversations {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    cursor: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    exclude_archived: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    limit: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    team_id: Option<String>,
+
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "super::serialize_comma_separated"
+    )]
+    #[builder(setter(strip_option), default)]
+    types: Option<Vec<ConversationType>>,
+
+    /// Defaults to the user who owns the token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    user: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct UsersConversationsResponse {
+    pub channels: Vec<Conversation>,
+    #[serde(default)]
+    pub response_metadata: ResponseMetadata,
+}
+
+impl Method for UsersConversations {
+    const NAME: &'static str = "users.conversations";
+    type Response = UsersConversationsResponse;
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Presence {
+    Active,
+    Away,
+}
+
+/// [https://api.slack.com/methods/users.getPresence](https://api.slack.com/methods/users.getPresence)
+#[derive(TypedBuilder, Serialize)]
+pub struct UsersGetPresence {
+    /// Defaults to the user who owns the token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    user: Option<String>,
+}
+
+/// Everything but `presence` is only returned when asking about the user who
+/// owns the token.
+#[derive(Deserialize, Debug, Clone)]
+pub struct UsersGetPresenceResponse {
+    pub presence: Presence,
+    #[serde(default)]
+    pub online: Option<bool>,
+    #[serde(default)]
+    pub auto_away: Option<bool>,
+    #[serde(default)]
+    pub manual_away: Option<bool>,
+    #[serde(default)]
+    pub connection_count: Option<i64>,
+    #[serde(default)]
+    pub last_activity: Option<i64>,
+}
+
+impl Method for UsersGetPresence {
+    const NAME: &'static str = "users.getPresence";
+    type Response = UsersGetPresenceResponse;
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SetPresence {
+    Auto,
+    Away,
+}
+
+/// [https://api.slack.com/methods/users.setPresence](https://api.slack.com/methods/users.setPresence)
+#[derive(TypedBuilder, Serialize)]
+pub struct UsersSetPresence {
+    presence: SetPresence,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct UsersSetPresenceResponse {}
+
+impl Method for UsersSetPresence {
+    const NAME: &'static str = "users.setPresence";
+    type Response = UsersSetPresenceResponse;
+}
+
+/// [https://api.slack.com/methods/users.profile.get](https://api.slack.com/methods/users.profile.get)
+#[derive(TypedBuilder, Serialize)]
+pub struct UsersProfileGet {
+    /// Populate `label` on each of the custom `fields`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    include_labels: Option<bool>,
+
+    /// Defaults to the user who owns the token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    user: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct UsersProfileGetResponse {
+    pub profile: UserProfile,
+}
+
+impl Method for UsersProfileGet {
+    const NAME: &'static str = "users.profile.get";
+    type Response = UsersProfileGetResponse;
+}
+
+/// [https://api.slack.com/methods/users.profile.set](https://api.slack.com/methods/users.profile.set)
+///
+/// Either set several fields at once with `profile`, or a single one with
+/// `name` and `value`.
+///
+/// ```
+/// # use assert_json_diff::assert_json_eq;
+/// # use serde_json::json;
+/// # use slack_lib::web_api::users::*;
+/// let request = UsersProfileSet::builder()
+///     .user("W012A3CDE")
+///     .name("Xf06054BBB")
+///     .value("On call")
+///     .build();
+///
+/// let expected = json!({
+///   "user": "W012A3CDE",
+///   "name": "Xf06054BBB",
+///   "value": "On call"
+/// });
+///
+/// let output = serde_json::to_value(&request).unwrap();
+/// assert_json_eq!(expected, output);
+/// ```
+#[derive(TypedBuilder, Serialize)]
+pub struct UsersProfileSet {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    profile: Option<UserProfile>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    value: Option<String>,
+
+    /// Only admins on paid teams can set other users' profiles
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    user: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct UsersProfileSetResponse {
+    pub profile: UserProfile,
+}
+
+impl Method for UsersProfileSet {
+    const NAME: &'static str = "users.profile.set";
+    type Response = UsersProfileSetResponse;
+}
@@ -0,0 +1,346 @@
+//! `views.*` methods, for opening, pushing and updating modals, and publishing
+//! home tabs.
+//!
+//! The views themselves are built with [`Modal`] and [`HomeTab`] from
+//! [`block_kit::views`](crate::block_kit::views).
+
+use super::Method;
+use crate::block_kit::views::{HomeTab, Modal, View};
+use crate::interactivity::State;
+use serde_json::Value;
+
+/// A view as Slack echoes it back: whatever was sent, along with the `id` and
+/// `hash` Slack assigned it.
+///
+/// Hold on to `id` and `hash` to update the view later with [`ViewsUpdate`].
+///
+/// Block and element types that aren't modelled yet are dropped, rather than
+/// failing the whole payload: a section just loses its accessory, but an input
+/// goes along with its element. Their values still show up in `state`.
+///
+/// ```
+/// # use slack_lib::block_kit::views::View;
+/// # use slack_lib::web_api::views::*;
+/// let view: RenderedView = serde_json::from_value(serde_json::json!({
+///   "id": "VMHU10V25",
+///   "team_id": "T8N4K1JN",
+///   "type": "modal",
+///   "title": { "type": "plain_text", "text": "Quite a plain modal" },
+///   "submit": { "type": "plain_text", "text": "Create" },
+///   "blocks": [
+///     {
+///       "type": "input",
+///       "block_id": "a_block_id",
+///       "label": { "type": "plain_text", "text": "A simple label", "emoji": true },
+///       "optional": false,
+///       "element": { "type": "plain_text_input", "action_id": "an_action_id" }
+///     }
+///   ],
+///   "private_metadata": "Shh it is a secret",
+///   "callback_id": "identify_your_modals",
+///   "state": { "values": {} },
+///   "hash": "156772938.1827394",
+///   "clear_on_close": false,
+///   "notify_on_close": false,
+///   "root_view_id": "VMHU10V25",
+///   "app_id": "AA4928AQ",
+///   "bot_id": "BA13894H"
+/// }))
+/// .unwrap();
+///
+/// assert_eq!(view.id, "VMHU10V25");
+/// assert_eq!(view.hash, "156772938.1827394");
+/// assert!(matches!(view.view, View::Modal(_)));
+/// assert_eq!(view.callback_id(), "identify_your_modals");
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct RenderedView {
+    pub id: String,
+    #[serde(default)]
+    pub hash: String,
+    #[serde(default)]
+    pub team_id: Option<String>,
+    #[serde(default)]
+    pub app_id: Option<String>,
+    #[serde(default)]
+    pub app_installed_team_id: Option<String>,
+    #[serde(default)]
+    pub bot_id: Option<String>,
+    #[serde(default)]
+    pub previous_view_id: Option<String>,
+    #[serde(default)]
+    pub root_view_id: Option<String>,
+    /// The values of the view's inputs
+    #[serde(default)]
+    pub state: Option<State>,
+    /// The modal or home tab itself
+    #[serde(flatten, deserialize_with = "deserialize_view")]
+    pub view: View,
+}
+
+impl RenderedView {
+    /// The `callback_id` of the modal or home tab, or `""` if it doesn't have
+    /// one
+    pub fn callback_id(&self) -> &str {
+        let callback_id = match &self.view {
+            View::Modal(modal) => &modal.callback_id,
+            View::HomeTab(home) => &home.callback_id,
+        };
+        callback_id.as_deref().unwrap_or_default()
+    }
+
+    /// The `private_metadata` of the modal or home tab, or `""` if it doesn't
+    /// have any
+    pub fn private_metadata(&self) -> &str {
+        let private_metadata = match &self.view {
+            View::Modal(modal) => &modal.private_metadata,
+            View::HomeTab(home) => &home.private_metadata,
+        };
+        private_metadata.as_deref().unwrap_or_default()
+    }
+}
+
+/// The block types modelled by [`ModalBlock`] and [`HomeTabBlock`]
+///
+/// [`ModalBlock`]: crate::block_kit::blocks::ModalBlock
+/// [`HomeTabBlock`]: crate::block_kit::blocks::HomeTabBlock
+const BLOCK_TYPES: &[&str] = &[
+    "actions",
+    "context",
+    "divider",
+    "header",
+    "image",
+    "input",
+    "rich_text",
+    "section",
+];
+
+/// The element types modelled in [`elements`](crate::block_kit::elements)
+const ELEMENT_TYPES: &[&str] = &[
+    "button",
+    "channels_select",
+    "checkboxes",
+    "conversations_select",
+    "datepicker",
+    "external_select",
+    "image",
+    "multi_channels_select",
+    "multi_conversations_select",
+    "multi_external_select",
+    "multi_static_select",
+    "multi_users_select",
+    "overflow",
+    "plain_text_input",
+    "radio_buttons",
+    "static_select",
+    "users_select",
+];
+
+/// Parses the view after dropping the blocks and elements that aren't
+/// modelled. Anything else that doesn't parse is still an error.
+fn deserialize_view<'de, D>(deserializer: D) -> Result<View, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::Error;
+
+    let mut view: Value = serde::Deserialize::deserialize(deserializer)?;
+    if let Some(blocks) = view.get_mut("blocks").and_then(Value::as_array_mut) {
+        blocks.retain_mut(strip_unmodelled);
+    }
+
+    serde_json::from_value(view).map_err(D::Error::custom)
+}
+
+/// Drops a block's unmodelled elements, returning whether the block is worth
+/// keeping
+fn strip_unmodelled(block: &mut Value) -> bool {
+    if !has_type(block, BLOCK_TYPES) {
+        return false;
+    }
+    let block = match block.as_object_mut() {
+        Some(block) => block,
+        None => return true,
+    };
+
+    if matches!(block.get("accessory"), Some(accessory) if !has_type(accessory, ELEMENT_TYPES)) {
+        block.remove("accessory");
+    }
+    if block.get("type").and_then(Value::as_str) == Some("actions") {
+        if let Some(elements) = block.get_mut("elements").and_then(Value::as_array_mut) {
+            elements.retain(|element| has_type(element, ELEMENT_TYPES));
+        }
+    }
+    match block.get("element") {
+        Some(element) => has_type(element, ELEMENT_TYPES),
+        None => true,
+    }
+}
+
+fn has_type(value: &Value, types: &[&str]) -> bool {
+    match value.get("type").and_then(Value::as_str) {
+        Some(kind) => types.contains(&kind),
+        None => false,
+    }
+}
+
+/// Which interaction a modal is opened (or pushed) in response to
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum ViewTarget {
+    /// From an interaction payload or slash command. Expires after three
+    /// seconds.
+    TriggerId(String),
+    /// From a workflow step
+    InteractivityPointer(String),
+}
+
+/// Which view to update: by the `id` Slack gave it, or the `external_id` you
+/// gave it
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum ViewRef {
+    ViewId(String),
+    ExternalId(String),
+}
+
+/// What all of the `views.*` methods respond with
+#[derive(Deserialize, Debug, Clone)]
+pub struct ViewsResponse {
+    pub view: RenderedView,
+}
+
+/// [https://api.slack.com/methods/views.open](https://api.slack.com/methods/views.open)
+///
+/// ```
+/// # use assert_json_diff::assert_json_eq;
+/// # use serde_json::json;
+/// # use slack_lib::block_kit::blocks::*;
+/// # use slack_lib::block_kit::views::*;
+/// # use slack_lib::web_api::views::*;
+/// let request = ViewsOpen::builder()
+///     .target(ViewTarget::TriggerId(String::from("12345.98765.abcd2358fdea")))
+///     .view(
+///         Modal::builder()
+///             .title("My App")
+///             .blocks(vec![Divider::builder().build().into()])
+///             .build(),
+///     )
+///     .build();
+///
+/// let expected = json!({
+///   "trigger_id": "12345.98765.abcd2358fdea",
+///   "view": {
+///     "type": "modal",
+///     "title": { "type": "plain_text", "text": "My App" },
+///     "blocks": [{ "type": "divider" }]
+///   }
+/// });
+///
+/// let output = serde_json::to_value(&request).unwrap();
+/// assert_json_eq!(expected, output);
+/// ```
+#[derive(TypedBuilder, Serialize)]
+pub struct ViewsOpen {
+    #[serde(flatten)]
+    target: ViewTarget,
+
+    view: Modal,
+}
+
+impl Method for ViewsOpen {
+    const NAME: &'static str = "views.open";
+    type Response = ViewsResponse;
+}
+
+/// [https://api.slack.com/methods/views.push](https://api.slack.com/methods/views.push)
+///
+/// Pushes a new modal onto the stack of an already open one. Slack allows up
+/// to 3 modals in a stack.
+#[derive(TypedBuilder, Serialize)]
+pub struct ViewsPush {
+    #[serde(flatten)]
+    target: ViewTarget,
+
+    view: Modal,
+}
+
+impl Method for ViewsPush {
+    const NAME: &'static str = "views.push";
+    type Response = ViewsResponse;
+}
+
+/// [https://api.slack.com/methods/views.update](https://api.slack.com/methods/views.update)
+///
+/// Pass the `hash` from the last [`RenderedView`] you saw to make sure you're
+/// not overwriting someone else's update. If the view has changed since, the
+/// call fails with `hash_conflict`.
+///
+/// ```
+/// # use assert_json_diff::assert_json_eq;
+/// # use serde_json::json;
+/// # use slack_lib::block_kit::blocks::*;
+/// # use slack_lib::block_kit::views::*;
+/// # use slack_lib::web_api::views::*;
+/// let request = ViewsUpdate::builder()
+///     .target(ViewRef::ExternalId(String::from("bmarley_view2")))
+///     .hash("156772938.1827394")
+///     .view(
+///         Modal::builder()
+///             .title("Updated view")
+///             .blocks(vec![Divider::builder().build().into()])
+///             .build(),
+///     )
+///     .build();
+///
+/// let expected = json!({
+///   "external_id": "bmarley_view2",
+///   "hash": "156772938.1827394",
+///   "view": {
+///     "type": "modal",
+///     "title": { "type": "plain_text", "text": "Updated view" },
+///     "blocks": [{ "type": "divider" }]
+///   }
+/// });
+///
+/// let output = serde_json::to_value(&request).unwrap();
+/// assert_json_eq!(expected, output);
+/// ```
+#[derive(TypedBuilder, Serialize)]
+pub struct ViewsUpdate {
+    #[builder(setter(into))]
+    view: View,
+
+    #[serde(flatten)]
+    target: ViewRef,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    hash: Option<String>,
+}
+
+impl Method for ViewsUpdate {
+    const NAME: &'static str = "views.update";
+    type Response = ViewsResponse;
+}
+
+/// [https://api.slack.com/methods/views.publish](https://api.slack.com/methods/views.publish)
+///
+/// Publishes a home tab for a single user. Like [`ViewsUpdate`], `hash` guards
+/// against overwriting a newer version.
+#[derive(TypedBuilder, Serialize)]
+pub struct ViewsPublish {
+    #[builder(setter(into))]
+    user_id: String,
+
+    view: HomeTab,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    hash: Option<String>,
+}
+
+impl Method for ViewsPublish {
+    const NAME: &'static str = "views.publish";
+    type Response = ViewsResponse;
+}
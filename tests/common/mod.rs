@@ -0,0 +1,236 @@
+//! A tiny stand-in for Slack's HTTP endpoints. It answers every request with
+//! the next canned response, and records what it was sent.
+
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub path: String,
+    pub headers: HashMap<String, String>,
+    pub body: Vec<u8>,
+}
+
+impl RecordedRequest {
+    pub fn body_text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+
+    /// Parse a form-encoded body into its fields
+    pub fn form(&self) -> HashMap<String, String> {
+        url_decode_form(&self.body_text())
+    }
+
+    pub fn json(&self) -> serde_json::Value {
+        serde_json::from_slice(&self.body).unwrap()
+    }
+}
+
+pub struct CannedResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl CannedResponse {
+    pub fn json(body: serde_json::Value) -> Self {
+        Self {
+            status: 200,
+            headers: vec![("content-type".into(), "application/json".into())],
+            body: body.to_string(),
+        }
+    }
+
+    pub fn text(status: u16, body: &str) -> Self {
+        Self {
+            status,
+            headers: vec![("content-type".into(), "text/plain".into())],
+            body: body.into(),
+        }
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+}
+
+pub struct StubServer {
+    pub url: String,
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+}
+
+impl StubServer {
+    pub async fn start(responses: Vec<CannedResponse>) -> Self {
+        Self::start_with(|_| responses).await
+    }
+
+    /// Like `start`, for when the responses need to point back at the server
+    pub async fn start_with(responses: impl FnOnce(&str) -> Vec<CannedResponse>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let responses = responses(&url);
+        let requests = Arc::new(Mutex::new(vec![]));
+        let recorded = requests.clone();
+
+        tokio::spawn(async move {
+            for response in responses {
+                let (mut socket, _) = match listener.accept().await {
+                    Ok(accepted) => accepted,
+                    Err(_) => return,
+                };
+                let request = read_request(&mut socket).await;
+                recorded.lock().unwrap().push(request);
+
+                let mut head = format!(
+                    "HTTP/1.1 {} OK\r\ncontent-length: {}\r\nconnection: close\r\n",
+                    response.status,
+                    response.body.len()
+                );
+                for (name, value) in &response.headers {
+                    head.push_str(&format!("{}: {}\r\n", name, value));
+                }
+                head.push_str("\r\n");
+                socket.write_all(head.as_bytes()).await.unwrap();
+                socket.write_all(response.body.as_bytes()).await.unwrap();
+                socket.shutdown().await.ok();
+            }
+        });
+
+        Self { url, requests }
+    }
+
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+async fn read_request(socket: &mut tokio::net::TcpStream) -> RecordedRequest {
+    let mut buffer = vec![];
+    let mut chunk = [0u8; 4096];
+    let header_end = loop {
+        let read = socket.read(&mut chunk).await.unwrap();
+        buffer.extend_from_slice(&chunk[..read]);
+        if let Some(position) = find(&buffer, b"\r\n\r\n") {
+            break position + 4;
+        }
+        if read == 0 {
+            panic!("connection closed before headers were sent");
+        }
+    };
+
+    let head = String::from_utf8_lossy(&buffer[..header_end]).into_owned();
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next().unwrap().split(' ');
+    let method = request_line.next().unwrap().to_owned();
+    let path = request_line.next().unwrap().to_owned();
+    let headers: HashMap<String, String> = lines
+        .filter_map(|line| {
+            let mut parts = line.splitn(2, ':');
+            let name = parts.next()?.trim().to_ascii_lowercase();
+            let value = parts.next()?.trim().to_owned();
+            if name.is_empty() {
+                None
+            } else {
+                Some((name, value))
+            }
+        })
+        .collect();
+
+    let mut body = buffer[header_end..].to_vec();
+    if let Some(length) = headers.get("content-length") {
+        let length: usize = length.parse().unwrap();
+        while body.len() < length {
+            let read = socket.read(&mut chunk).await.unwrap();
+            if read == 0 {
+                break;
+            }
+            body.extend_from_slice(&chunk[..read]);
+        }
+    } else if headers.get("transfer-encoding").map(String::as_str) == Some("chunked") {
+        body = read_chunked(socket, body).await;
+    }
+
+    RecordedRequest {
+        method,
+        path,
+        headers,
+        body,
+    }
+}
+
+async fn read_chunked(socket: &mut tokio::net::TcpStream, mut raw: Vec<u8>) -> Vec<u8> {
+    let mut chunk = [0u8; 4096];
+    loop {
+        if let Some(body) = decode_chunked(&raw) {
+            return body;
+        }
+        let read = socket.read(&mut chunk).await.unwrap();
+        if read == 0 {
+            return decode_chunked(&raw).unwrap_or(raw);
+        }
+        raw.extend_from_slice(&chunk[..read]);
+    }
+}
+
+/// Returns the decoded body once the terminating zero-length chunk is seen
+fn decode_chunked(raw: &[u8]) -> Option<Vec<u8>> {
+    let mut body = vec![];
+    let mut position = 0;
+    loop {
+        let line_end = position + find(&raw[position..], b"\r\n")?;
+        let size = std::str::from_utf8(&raw[position..line_end]).ok()?;
+        let size = usize::from_str_radix(size.trim(), 16).ok()?;
+        let start = line_end + 2;
+        if size == 0 {
+            return Some(body);
+        }
+        if raw.len() < start + size + 2 {
+            return None;
+        }
+        body.extend_from_slice(&raw[start..start + size]);
+        position = start + size + 2;
+    }
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+fn url_decode_form(body: &str) -> HashMap<String, String> {
+    body.split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let mut parts = pair.splitn(2, '=');
+            let key = url_decode(parts.next().unwrap_or(""));
+            let value = url_decode(parts.next().unwrap_or(""));
+            (key, value)
+        })
+        .collect()
+}
+
+fn url_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = vec![];
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'+' => decoded.push(b' '),
+            b'%' if index + 2 < bytes.len() => {
+                let hex = std::str::from_utf8(&bytes[index + 1..index + 3]).unwrap();
+                decoded.push(u8::from_str_radix(hex, 16).unwrap());
+                index += 2;
+            }
+            byte => decoded.push(byte),
+        }
+        index += 1;
+    }
+    String::from_utf8(decoded).unwrap()
+}
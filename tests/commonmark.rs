@@ -0,0 +1,127 @@
+use serde_json::json;
+use slack_lib::block_kit::blocks::MessageBlock;
+use slack_lib::mrkdwn::commonmark::*;
+
+fn section_text(block: &MessageBlock) -> String {
+    serde_json::to_value(block).unwrap()["text"]["text"]
+        .as_str()
+        .unwrap()
+        .to_owned()
+}
+
+#[test]
+fn converts_nested_markdown() {
+    let blocks = to_blocks(
+        "Intro with ~~old~~ *new* text & <tags>, and a [![badge](https://example.com/b.svg)](https://ci.example.com).\n\
+         \n\
+         - [x] one\n\
+         - two\n\
+         \x20 1. nested\n\
+         \x20 2. again\n\
+         \n\
+         > quoted **bold**\n\
+         > over lines\n\
+         \n\
+         ```rust\n\
+         let x = a < b;\n\
+         ```\n\
+         \n\
+         | Name | Value |\n\
+         |------|-------|\n\
+         | a    | 1     |\n\
+         | long | 22    |\n",
+    );
+
+    assert_eq!(blocks.len(), 1);
+    assert_eq!(
+        section_text(&blocks[0]),
+        "Intro with ~old~ _new_ text &amp; &lt;tags&gt;, and a <https://ci.example.com|badge>.\n\
+         \n\
+         \u{2022} \u{2611} one\n\
+         \u{2022} two\n\
+         \x20   1. nested\n\
+         \x20   2. again\n\
+         \n\
+         > quoted *bold* over lines\n\
+         \n\
+         ```\n\
+         let x = a &lt; b;\n\
+         ```\n\
+         \n\
+         ```\n\
+         Name | Value\n\
+         -----+------\n\
+         a    | 1\n\
+         long | 22\n\
+         ```"
+    );
+}
+
+#[test]
+fn splits_long_sections() {
+    let paragraph = "word ".repeat(500);
+    let markdown = format!(
+        "{}\n\n{}\n\n```\n{}\n```",
+        paragraph,
+        paragraph,
+        "x\n".repeat(2000)
+    );
+    let blocks = to_blocks(&markdown);
+
+    assert!(blocks.len() > 2);
+    for block in &blocks {
+        let text = section_text(block);
+        assert!(text.chars().count() <= SECTION_LIMIT);
+        assert_eq!(
+            text.matches("```").count() % 2,
+            0,
+            "unbalanced fence in {:?}",
+            text
+        );
+    }
+    // Each paragraph is too long to share a section, but fits in one
+    assert_eq!(section_text(&blocks[0]), paragraph.trim());
+    assert_eq!(section_text(&blocks[1]), paragraph.trim());
+}
+
+#[test]
+fn splits_long_code_lines() {
+    let line = "A".repeat(5000);
+    let blocks = to_blocks(&format!("```\n{}\n```", line));
+
+    assert_eq!(blocks.len(), 2);
+    let mut code = String::new();
+    for block in &blocks {
+        let text = section_text(block);
+        assert!(text.chars().count() <= SECTION_LIMIT);
+        code.push_str(
+            text.strip_prefix("```\n")
+                .and_then(|text| text.strip_suffix("\n```"))
+                .unwrap(),
+        );
+    }
+    assert_eq!(code, line);
+}
+
+#[test]
+fn truncates_long_headers() {
+    let blocks = to_blocks(&format!("# {}", "a".repeat(200)));
+    let header = serde_json::to_value(&blocks[0]).unwrap();
+
+    assert_eq!(header["type"], json!("header"));
+    let text = header["text"]["text"].as_str().unwrap();
+    assert_eq!(text.chars().count(), HEADER_LIMIT);
+    assert!(text.ends_with('\u{2026}'));
+}
+
+#[test]
+fn converts_mrkdwn_back() {
+    assert_eq!(
+        from_mrkdwn("&gt; quoted _text_\nafter\n\nnew paragraph with 2*3 and <#C111|general>"),
+        "> quoted _text_\n\nafter\n\nnew paragraph with 2\\*3 and #general"
+    );
+    assert_eq!(
+        from_mrkdwn("see\n```\nlet x = 1;\n```\n~gone~ <!date^1392734382^{date}|Feb 18>"),
+        "see\n\n```\nlet x = 1;\n```\n\n~~gone~~ Feb 18"
+    );
+}
@@ -0,0 +1,66 @@
+mod common;
+
+use common::{CannedResponse, StubServer};
+use serde_json::json;
+use slack_lib::block_kit::blocks::Section;
+use slack_lib::block_kit::composition::Markdown;
+use slack_lib::web_api::files::FileUpload;
+use slack_lib::web_api::Client;
+
+#[tokio::test]
+async fn uploads_files_in_three_steps() {
+    let server = StubServer::start_with(|url| {
+        vec![
+            CannedResponse::json(json!({
+                "ok": true,
+                "upload_url": format!("{}upload/v1/abc", url),
+                "file_id": "F123ABC456"
+            })),
+            CannedResponse::text(200, "OK - 11"),
+            CannedResponse::json(json!({
+                "ok": true,
+                "files": [{ "id": "F123ABC456", "title": "Greeting" }]
+            })),
+        ]
+    })
+    .await;
+    let client = Client::new("xoxb-token").base_url(&server.url);
+
+    let contents: &'static [u8] = b"hello world";
+    let files = client
+        .upload_file(
+            FileUpload::builder()
+                .reader(contents)
+                .filename("hello.txt")
+                .length(contents.len() as u64)
+                .title("Greeting")
+                .channel_id("C0NF841BK")
+                .initial_comment("Here you go")
+                .blocks(vec![Section::builder()
+                    .text(Markdown::from("*A file*"))
+                    .build()
+                    .into()])
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(files[0].id, "F123ABC456");
+
+    let requests = server.requests();
+    assert_eq!(requests[0].path, "/files.getUploadURLExternal");
+    let form = requests[0].form();
+    assert_eq!(form["filename"], "hello.txt");
+    assert_eq!(form["length"], "11");
+
+    assert_eq!(requests[1].path, "/upload/v1/abc");
+    assert_eq!(requests[1].body, contents);
+
+    assert_eq!(requests[2].path, "/files.completeUploadExternal");
+    let form = requests[2].form();
+    assert_eq!(form["files"], r#"[{"id":"F123ABC456","title":"Greeting"}]"#);
+    assert_eq!(form["channel_id"], "C0NF841BK");
+    assert_eq!(form["initial_comment"], "Here you go");
+    let blocks: serde_json::Value = serde_json::from_str(&form["blocks"]).unwrap();
+    assert_eq!(blocks[0]["text"]["text"], "*A file*");
+}
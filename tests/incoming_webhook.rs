@@ -0,0 +1,70 @@
+mod common;
+
+use common::{CannedResponse, StubServer};
+use serde_json::json;
+use slack_lib::incoming_webhook::*;
+
+#[tokio::test]
+async fn posts_messages() {
+    let server = StubServer::start(vec![CannedResponse::text(200, "ok")]).await;
+    let webhook = IncomingWebhook::new(format!("{}services/T000/B000/XXXX", server.url));
+
+    webhook
+        .send(
+            &WebhookMessage::builder()
+                .content("Disk usage is at 95%")
+                .attachments(vec![Attachment::builder().color("warning").build()])
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].path, "/services/T000/B000/XXXX");
+    assert_eq!(
+        requests[0].json(),
+        json!({
+            "text": "Disk usage is at 95%",
+            "attachments": [{ "color": "warning" }]
+        })
+    );
+}
+
+#[tokio::test]
+async fn parses_plain_text_errors() {
+    let server = StubServer::start(vec![
+        CannedResponse::text(400, "invalid_payload"),
+        CannedResponse::text(400, "no_text"),
+        CannedResponse::text(410, "channel_is_archived"),
+        CannedResponse::text(500, "internal_error"),
+        CannedResponse::text(429, "rate_limited").header("retry-after", "30"),
+    ])
+    .await;
+    let webhook = IncomingWebhook::new(server.url.clone());
+    let message = WebhookMessage::builder()
+        .content("Disk usage is at 95%")
+        .build();
+
+    assert!(matches!(
+        webhook.send(&message).await,
+        Err(Error::InvalidPayload)
+    ));
+    assert!(matches!(webhook.send(&message).await, Err(Error::NoText)));
+    assert!(matches!(
+        webhook.send(&message).await,
+        Err(Error::ChannelIsArchived)
+    ));
+    match webhook.send(&message).await {
+        Err(Error::Other { status, error }) => {
+            assert_eq!(status, 500);
+            assert_eq!(error, "internal_error");
+        }
+        other => panic!("expected internal_error, got {:?}", other),
+    }
+    assert!(matches!(
+        webhook.send(&message).await,
+        Err(Error::RateLimited {
+            retry_after: Some(30)
+        })
+    ));
+}
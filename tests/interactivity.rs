@@ -0,0 +1,89 @@
+use serde_json::json;
+use slack_lib::block_kit::blocks::ModalBlock;
+use slack_lib::block_kit::views::View;
+use slack_lib::interactivity::*;
+
+#[test]
+fn parses_view_submission_with_unmodelled_blocks() {
+    let payload: Payload = serde_json::from_value(json!({
+        "type": "view_submission",
+        "team": { "id": "T123ABC456", "domain": "example" },
+        "user": { "id": "U123ABC456" },
+        "api_app_id": "A123ABC456",
+        "view": {
+            "id": "V123ABC456",
+            "type": "modal",
+            "title": { "type": "plain_text", "text": "Order" },
+            "blocks": [
+                {
+                    "type": "input",
+                    "block_id": "quantity",
+                    "label": { "type": "plain_text", "text": "Quantity" },
+                    "element": { "type": "number_input", "action_id": "value", "is_decimal_allowed": false }
+                },
+                {
+                    "type": "section",
+                    "fields": [{ "type": "mrkdwn", "text": "*Price*\n$4" }],
+                    "accessory": {
+                        "type": "workflow_button",
+                        "text": { "type": "plain_text", "text": "Reorder" },
+                        "workflow": { "trigger": { "url": "https://slack.com/shortcuts/Ft0123ABC456/abc" } }
+                    }
+                },
+                {
+                    "type": "video",
+                    "title": { "type": "plain_text", "text": "How to order" },
+                    "video_url": "https://example.com/embed",
+                    "thumbnail_url": "https://example.com/thumb.png",
+                    "alt_text": "How to order"
+                },
+                {
+                    "type": "input",
+                    "block_id": "notes",
+                    "label": { "type": "plain_text", "text": "Notes" },
+                    "element": { "type": "plain_text_input", "action_id": "value" }
+                }
+            ],
+            "state": {
+                "values": {
+                    "quantity": { "value": { "type": "number_input", "value": "3" } },
+                    "notes": { "value": { "type": "plain_text_input", "value": "Gift wrap" } }
+                }
+            },
+            "hash": "156663117.cd33ad1f"
+        }
+    }))
+    .unwrap();
+
+    let submission = match payload {
+        Payload::ViewSubmission(submission) => submission,
+        _ => panic!("expected view_submission"),
+    };
+
+    let modal = match &submission.view.view {
+        View::Modal(modal) => modal,
+        _ => panic!("expected a modal"),
+    };
+    assert_eq!(modal.blocks.len(), 2);
+    match &modal.blocks[0] {
+        ModalBlock::Section(section) => {
+            assert!(section.text.is_none());
+            assert_eq!(section.fields.as_ref().unwrap().len(), 1);
+            assert!(section.accessory.is_none());
+        }
+        other => panic!("expected the section, got {:?}", other),
+    }
+    assert!(matches!(modal.blocks[1], ModalBlock::Input(_)));
+
+    let state = submission.view.state.unwrap();
+    assert!(matches!(
+        state.get("quantity", "value"),
+        Some(ActionValue::Unknown)
+    ));
+    match state.get("notes", "value") {
+        Some(ActionValue::PlainTextInput { value }) => {
+            assert_eq!(value.as_deref(), Some("Gift wrap"))
+        }
+        _ => panic!("expected a plain_text_input"),
+    }
+}
@@ -0,0 +1,123 @@
+use slack_lib::mrkdwn::parser::*;
+use slack_lib::mrkdwn::MrkdwnBuilder;
+
+fn text(text: &str) -> Node {
+    Node::Text(String::from(text))
+}
+
+#[test]
+fn parses_builder_output() {
+    let built = MrkdwnBuilder::new()
+        .bold("a < b")
+        .text(" ")
+        .link("https://example.com/?a=1&b=2", "docs")
+        .text(" ")
+        .usergroup("SAZ94GDB8")
+        .text(" ")
+        .date(1392734382, "{date_num}", "2014-02-18")
+        .emoji("+1")
+        .emoji("skin-tone-2")
+        .quote("one\ntwo")
+        .build();
+
+    assert_eq!(
+        parse(&built.text),
+        vec![
+            Node::Bold(vec![text("a < b")]),
+            text(" "),
+            Node::Link {
+                url: String::from("https://example.com/?a=1&b=2"),
+                label: Some(String::from("docs")),
+            },
+            text(" "),
+            Node::Usergroup {
+                id: String::from("SAZ94GDB8"),
+                label: None,
+            },
+            text(" "),
+            Node::Date {
+                ts: 1392734382,
+                format: String::from("{date_num}"),
+                link: None,
+                fallback: String::from("2014-02-18"),
+            },
+            Node::Emoji(String::from("+1")),
+            Node::Emoji(String::from("skin-tone-2")),
+            Node::LineBreak,
+            Node::Quote(vec![text("one"), Node::LineBreak, text("two")]),
+            Node::LineBreak,
+        ]
+    );
+}
+
+#[test]
+fn handles_slack_quirks() {
+    // Markers inside words, and unclosed ones, are just text
+    assert_eq!(
+        parse("snake_case_name and 2*3 and *unclosed"),
+        vec![text("snake_case_name and 2*3 and *unclosed")]
+    );
+
+    // Styles nest, but don't carry across lines
+    assert_eq!(
+        parse("*bold _both_*\n~not\nstruck~"),
+        vec![
+            Node::Bold(vec![text("bold "), Node::Italic(vec![text("both")])]),
+            Node::LineBreak,
+            text("~not"),
+            Node::LineBreak,
+            text("struck~"),
+        ]
+    );
+
+    // Nothing is formatted in code, and markers in code don't close styles
+    assert_eq!(
+        parse("*see `a*b` <#C024BE7LR|general>* `<@U111>`"),
+        vec![
+            Node::Bold(vec![
+                text("see "),
+                Node::Code(String::from("a*b")),
+                text(" "),
+                Node::Channel {
+                    id: String::from("C024BE7LR"),
+                    label: Some(String::from("general")),
+                },
+            ]),
+            text(" "),
+            Node::Code(String::from("<@U111>")),
+        ]
+    );
+
+    // Times aren't emoji, and inline code blocks work
+    assert_eq!(
+        parse("at 10:30:00 ```x &amp;&amp; y```"),
+        vec![
+            text("at 10:30:00 "),
+            Node::CodeBlock(String::from("x && y"))
+        ]
+    );
+
+    assert_eq!(
+        parse("<!channel> <!everyone|everyone> <mailto:bob@example.com>"),
+        vec![
+            Node::Broadcast(Broadcast::Channel),
+            text(" "),
+            Node::Broadcast(Broadcast::Everyone),
+            text(" "),
+            Node::Link {
+                url: String::from("mailto:bob@example.com"),
+                label: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn parses_unclosed_markers_in_linear_time() {
+    // Each of these used to rescan the rest of the line
+    let line = "*a _b ~c <d ".repeat(20000);
+    let start = std::time::Instant::now();
+    let nodes = parse(&line);
+    assert!(start.elapsed() < std::time::Duration::from_secs(1));
+    assert_eq!(nodes, vec![text(&line)]);
+}
@@ -0,0 +1,172 @@
+mod common;
+
+use common::{CannedResponse, StubServer};
+use serde_json::json;
+use slack_lib::openid::*;
+use slack_lib::web_api::Client;
+use std::time::{Duration, UNIX_EPOCH};
+
+/// Signed with the key in `jwks()`. Issued at 1626874655, expiring five
+/// minutes later, with the nonce `abcd`.
+const ID_TOKEN: &str = "eyJhbGciOiJSUzI1NiIsImtpZCI6Im1CMk1BeUtTbjU1NWlzZDBFYmRoS3g2bmt5QWk5eExxOHJ2Q0ViX25PeVkiLCJ0eXAiOiJKV1QifQ.eyJpc3MiOiJodHRwczovL3NsYWNrLmNvbSIsInN1YiI6IlUwUjdNRk1KTSIsImF1ZCI6IjI1MjU5NTMxNTY5LjExMTUyNTgyNDYyOTEiLCJleHAiOjE2MjY4NzQ5NTUsImlhdCI6MTYyNjg3NDY1NSwiYXV0aF90aW1lIjoxNjI2ODc0NjU1LCJub25jZSI6ImFiY2QiLCJhdF9oYXNoIjoiYWJjIiwiaHR0cHM6Ly9zbGFjay5jb20vdGVhbV9pZCI6IlQwUjdHUiIsImh0dHBzOi8vc2xhY2suY29tL3VzZXJfaWQiOiJVMFI3TUZNSk0iLCJlbWFpbCI6ImFsaWNlQGV4YW1wbGUuY29tIiwiZW1haWxfdmVyaWZpZWQiOnRydWUsImRhdGVfZW1haWxfdmVyaWZpZWQiOjE2MjIxMjg3MjMsImxvY2FsZSI6ImVuLVVTIiwibmFtZSI6IkFsaWNlIiwiZ2l2ZW5fbmFtZSI6IkFsaWNlIiwiZmFtaWx5X25hbWUiOiIiLCJodHRwczovL3NsYWNrLmNvbS90ZWFtX25hbWUiOiJrcmFuZWZsYW5uZWwifQ.VQXCrLUx778u1oDblTUprio1zDz0WSB0Lez-1pRN5PRrceArILWLn_hmm80AiK8_K6O5TUZYuidMESHbePM6W1wCllR8x3m_SxOUBzr0A9NBNAjnty6mU0nlZrxE5meuhEIQpSFnpuzhFhHDd1EYOWOyF2tNBrPXceCpJEq3rGHH60BiY12BUvo7DSjUscOdioPhKlYaJk4-bGTPRZxFFCYtPNWm5d3dEE4ajFnNqggWXmeiMRb4TbOYXZjUEObyjn2obJQ-85HLCCJJFzqCO7IzyNEL7AswRD4_i-tIu4Sm8BDeYCfqBVUEN8M0GCVTM_UCplH754Db2Jc1D4xAeg";
+
+fn jwks() -> Jwks {
+    serde_json::from_value(json!({
+        "keys": [{
+            "e": "AQAB",
+            "n": "wSErV-d3P1PeJ0jIVsZ-tf7odA9Lz4ctBsVZJZcQrOSLCItdTET_GAYCIdHqJwE0b2QdhL0XSCH8oT4lDYVbaJ5MXvCGVgJcNRIma01QWFE1V53DEuFp1Mk2hA_7wgowlHoOqOOFcJUapATaJ5ATzA9-VpX0HNWYuBEOEPzo9B2LEwKpGOvhmmwCG0vQ76BFV38N8efoBdJd6--TTk3aRoamsAhjspEc1BiHMhyDfOGOWSOIcVI5zeo1QlcuTuzMSOxesOTGGYEbQlJpfGMNTIh0fYTR1p-99oVQZcE85vf2Pd26LZG0OE8VSuoggXbDhC6KbpUSY-gsjTVU16lkHw",
+            "kty": "RSA",
+            "kid": "mB2MAyKSn555isd0EbdhKx6nkyAi9xLq8rvCEb_nOyY",
+            "alg": "RS256"
+        }]
+    }))
+    .unwrap()
+}
+
+fn validator() -> IdTokenValidator {
+    IdTokenValidator::builder()
+        .client_id("25259531569.1115258246291")
+        .nonce("abcd")
+        .build()
+}
+
+#[test]
+fn validates_id_tokens() {
+    let issued = UNIX_EPOCH + Duration::from_secs(1626874655);
+
+    let claims = validator().validate_at(ID_TOKEN, &jwks(), issued).unwrap();
+    assert_eq!(claims.user_id, "U0R7MFMJM");
+    assert_eq!(claims.team_id, "T0R7GR");
+    assert_eq!(claims.aud, vec!["25259531569.1115258246291"]);
+    assert_eq!(claims.email.as_deref(), Some("alice@example.com"));
+    assert_eq!(claims.team_name.as_deref(), Some("kraneflannel"));
+
+    let later = issued + Duration::from_secs(60 * 60);
+    assert_eq!(
+        validator()
+            .validate_at(ID_TOKEN, &jwks(), later)
+            .unwrap_err(),
+        Error::Expired { exp: 1626874955 }
+    );
+
+    let other_nonce = IdTokenValidator::builder()
+        .client_id("25259531569.1115258246291")
+        .nonce("efgh")
+        .build();
+    assert_eq!(
+        other_nonce
+            .validate_at(ID_TOKEN, &jwks(), issued)
+            .unwrap_err(),
+        Error::Nonce
+    );
+
+    let other_client = IdTokenValidator::builder().client_id("111.222").build();
+    assert_eq!(
+        other_client
+            .validate_at(ID_TOKEN, &jwks(), issued)
+            .unwrap_err(),
+        Error::Audience
+    );
+
+    // Swap the claims for some the key didn't sign
+    let mut parts: Vec<&str> = ID_TOKEN.split('.').collect();
+    let forged = "eyJpc3MiOiJodHRwczovL3NsYWNrLmNvbSJ9";
+    parts[1] = forged;
+    assert_eq!(
+        validator()
+            .validate_at(&parts.join("."), &jwks(), issued)
+            .unwrap_err(),
+        Error::Mismatch
+    );
+
+    assert_eq!(
+        validator()
+            .validate_at(ID_TOKEN, &Jwks::default(), issued)
+            .unwrap_err(),
+        Error::UnknownKey(Some(String::from(
+            "mB2MAyKSn555isd0EbdhKx6nkyAi9xLq8rvCEb_nOyY"
+        )))
+    );
+    assert_eq!(
+        validator()
+            .validate_at("not.a-jwt", &jwks(), issued)
+            .unwrap_err(),
+        Error::Malformed("token")
+    );
+}
+
+#[tokio::test]
+async fn exchanges_code_and_fetches_user_info() {
+    let server = StubServer::start(vec![
+        CannedResponse::json(json!({
+            "ok": true,
+            "access_token": "xoxp-1234",
+            "token_type": "Bearer",
+            "id_token": ID_TOKEN
+        })),
+        CannedResponse::json(json!({
+            "ok": true,
+            "sub": "U0R7MFMJM",
+            "https://slack.com/user_id": "U0R7MFMJM",
+            "https://slack.com/team_id": "T0R7GR",
+            "email": "alice@example.com",
+            "email_verified": true,
+            "name": "Alice",
+            "https://slack.com/team_name": "kraneflannel"
+        })),
+    ])
+    .await;
+    let provider = SignInProvider::builder()
+        .client_id("25259531569.1115258246291")
+        .client_secret("33fea0113f5b1")
+        .redirect_uri("https://example.com/slack/sign_in")
+        .client(Client::new("").base_url(&server.url))
+        .build();
+
+    let tokens = provider.exchange_code("1234.5678").await.unwrap();
+    assert_eq!(tokens.id_token, ID_TOKEN);
+
+    let user = provider.user_info(&tokens.access_token).await.unwrap();
+    assert_eq!(user.user_id, "U0R7MFMJM");
+    assert_eq!(user.name.as_deref(), Some("Alice"));
+
+    let requests = server.requests();
+    assert_eq!(requests[0].path, "/openid.connect.token");
+    assert!(!requests[0].headers.contains_key("authorization"));
+    let form = requests[0].form();
+    assert_eq!(form["grant_type"], "authorization_code");
+    assert_eq!(form["code"], "1234.5678");
+    assert_eq!(form["redirect_uri"], "https://example.com/slack/sign_in");
+
+    assert_eq!(requests[1].path, "/openid.connect.userInfo");
+    assert_eq!(requests[1].headers["authorization"], "Bearer xoxp-1234");
+}
+
+#[test]
+fn generates_distinct_state_and_nonce() {
+    let (state, nonce) = (generate_state(), generate_nonce());
+    assert_eq!(state.len(), 32);
+    assert_ne!(state, nonce);
+}
+
+#[test]
+fn adds_to_a_custom_authorize_url() {
+    let provider = SignInProvider::builder()
+        .client_id("25259531569.1115258246291")
+        .client_secret("33fea0113f5b1")
+        .redirect_uri("https://example.com/slack/sign_in")
+        .scopes(vec![String::from("openid")])
+        .authorize_url(
+            "https://example.slack.com/openid/connect/authorize?team=T111"
+                .parse()
+                .unwrap(),
+        )
+        .build();
+
+    assert_eq!(
+        provider.authorize_url("abc", "xyz"),
+        "https://example.slack.com/openid/connect/authorize?team=T111\
+         &response_type=code&scope=openid&client_id=25259531569.1115258246291\
+         &state=abc&nonce=xyz&redirect_uri=https%3A%2F%2Fexample.com%2Fslack%2Fsign_in"
+    );
+}
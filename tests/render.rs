@@ -0,0 +1,461 @@
+use serde_json::json;
+use slack_lib::block_kit::blocks::{HomeTabBlock, MessageBlock, ModalBlock};
+use slack_lib::block_kit::render::terminal::Terminal;
+use slack_lib::block_kit::render::{html, text};
+use slack_lib::block_kit::views::Modal;
+
+#[test]
+fn renders_message_as_text() {
+    let blocks: Vec<MessageBlock> = serde_json::from_value(json!([
+        {
+            "type": "section",
+            "text": { "type": "mrkdwn", "text": "> *Heads up* <!channel>\n> see <https://example.com/status>" },
+            "accessory": {
+                "type": "overflow",
+                "action_id": "more",
+                "options": [{ "text": { "type": "plain_text", "text": "Mute" }, "value": "mute" }]
+            }
+        },
+        {
+            "type": "context",
+            "elements": [
+                { "type": "image", "image_url": "https://example.com/a.png", "alt_text": "avatar" },
+                { "type": "mrkdwn", "text": "Posted <!date^1392734382^{date_short}|Feb 18, 2014> :wave:" }
+            ]
+        },
+        {
+            "type": "image",
+            "image_url": "https://example.com/graph.png",
+            "alt_text": "Error rate over the last hour",
+            "title": { "type": "plain_text", "text": "Errors" }
+        },
+        {
+            "type": "actions",
+            "elements": [
+                {
+                    "type": "users_select",
+                    "action_id": "assignee",
+                    "placeholder": { "type": "plain_text", "text": "Assign to" },
+                    "initial_user": "U024BE7LH"
+                },
+                {
+                    "type": "static_select",
+                    "action_id": "priority",
+                    "placeholder": { "type": "plain_text", "text": "Priority" },
+                    "options": [{ "text": { "type": "plain_text", "text": "High" }, "value": "high" }]
+                },
+                {
+                    "type": "button",
+                    "action_id": "ack",
+                    "text": { "type": "plain_text", "text": "Acknowledge" },
+                    "style": "primary"
+                }
+            ]
+        },
+        {
+            "type": "rich_text",
+            "elements": [
+                {
+                    "type": "rich_text_section",
+                    "elements": [
+                        { "type": "text", "text": "Owners: " },
+                        { "type": "user", "user_id": "U024BE7LH" },
+                        { "type": "text", "text": "\n" }
+                    ]
+                },
+                {
+                    "type": "rich_text_list",
+                    "style": "ordered",
+                    "elements": [
+                        { "type": "rich_text_section", "elements": [{ "type": "text", "text": "Check dashboards" }] },
+                        { "type": "rich_text_section", "elements": [{ "type": "link", "url": "https://example.com/runbook" }] }
+                    ]
+                }
+            ]
+        }
+    ]))
+    .unwrap();
+
+    assert_eq!(
+        text::render(&blocks),
+        "> Heads up @channel\n\
+         > see https://example.com/status\n\
+         [\u{2026}]\n\
+         avatar Posted Feb 18, 2014 :wave:\n\
+         Errors\n\
+         Error rate over the last hour\n\
+         [@U024BE7LH \u{25be}] [Priority \u{25be}] [Acknowledge]\n\
+         Owners: @U024BE7LH\n\
+         1. Check dashboards\n\
+         2. https://example.com/runbook"
+    );
+}
+
+#[test]
+fn escapes_text_fallback() {
+    let blocks: Vec<MessageBlock> = serde_json::from_value(json!([
+        { "type": "header", "text": { "type": "plain_text", "text": "<!everyone>" } },
+        {
+            "type": "section",
+            "text": { "type": "mrkdwn", "text": "> hi &lt;!channel&gt; `&lt;@U111&gt;` <https://example.com|a &amp; b>" },
+            "accessory": {
+                "type": "button",
+                "action_id": "open",
+                "text": { "type": "plain_text", "text": "<#C024BE7LR>" }
+            }
+        }
+    ]))
+    .unwrap();
+
+    assert_eq!(
+        text::fallback(&blocks),
+        "&lt;!everyone&gt;\n\
+         &gt; hi &lt;!channel&gt; &lt;@U111&gt; a &amp; b\n\
+         [&lt;#C024BE7LR&gt;]"
+    );
+}
+
+#[test]
+fn renders_modal_inputs_as_text() {
+    let blocks: Vec<ModalBlock> = serde_json::from_value(json!([
+        {
+            "type": "input",
+            "label": { "type": "plain_text", "text": "Title" },
+            "element": {
+                "type": "plain_text_input",
+                "action_id": "title",
+                "placeholder": { "type": "plain_text", "text": "A short summary" }
+            },
+            "hint": { "type": "plain_text", "text": "Shown in the channel" }
+        },
+        {
+            "type": "input",
+            "label": { "type": "plain_text", "text": "Notify" },
+            "optional": true,
+            "element": {
+                "type": "checkboxes",
+                "action_id": "notify",
+                "options": [
+                    { "text": { "type": "mrkdwn", "text": "*Email*" }, "value": "email" },
+                    { "text": { "type": "plain_text", "text": "SMS" }, "value": "sms" }
+                ],
+                "initial_options": [
+                    { "text": { "type": "mrkdwn", "text": "*Email*" }, "value": "email" }
+                ]
+            }
+        },
+        {
+            "type": "input",
+            "label": { "type": "plain_text", "text": "Due" },
+            "element": { "type": "datepicker", "action_id": "due", "initial_date": "2024-03-01" }
+        }
+    ]))
+    .unwrap();
+
+    assert_eq!(
+        text::render(&blocks),
+        "Title: [A short summary]\n\
+         Shown in the channel\n\
+         Notify (optional): [x] Email [ ] SMS\n\
+         Due: [2024-03-01 \u{25be}]"
+    );
+}
+
+#[test]
+fn renders_home_tab_as_text() {
+    let blocks: Vec<HomeTabBlock> = serde_json::from_value(json!([
+        { "type": "header", "text": { "type": "plain_text", "text": "Your tasks" } },
+        {
+            "type": "section",
+            "text": { "type": "plain_text", "text": "Nothing due" },
+            "fields": [
+                { "type": "mrkdwn", "text": "*Open*\n0" },
+                { "type": "mrkdwn", "text": "*Done*\n`12`" }
+            ],
+            "accessory": {
+                "type": "radio_buttons",
+                "action_id": "filter",
+                "options": [
+                    { "text": { "type": "plain_text", "text": "Mine" }, "value": "mine" },
+                    { "text": { "type": "plain_text", "text": "All" }, "value": "all" }
+                ],
+                "initial_option": { "text": { "type": "plain_text", "text": "All" }, "value": "all" }
+            }
+        },
+        { "type": "divider" }
+    ]))
+    .unwrap();
+
+    assert_eq!(
+        text::render(&blocks),
+        "Your tasks\n\
+         Nothing due\n\
+         Open\n0\n\
+         Done\n12\n\
+         ( ) Mine (*) All\n\
+         ---"
+    );
+}
+
+#[test]
+fn renders_message_as_html() {
+    let blocks: Vec<MessageBlock> = serde_json::from_value(json!([
+        { "type": "header", "text": { "type": "plain_text", "text": "Q3 <report>" } },
+        {
+            "type": "section",
+            "text": { "type": "mrkdwn", "text": "> ~old~ *new* <#C024BE7LR|ops>\n```a < b```" },
+            "fields": [
+                { "type": "mrkdwn", "text": "*Owner*\n<!subteam^SAZ94GDB8>" },
+                { "type": "plain_text", "text": "\"Due\" soon" }
+            ],
+            "accessory": {
+                "type": "image",
+                "image_url": "https://example.com/chart.png?a=1&b=2",
+                "alt_text": "chart"
+            }
+        },
+        {
+            "type": "context",
+            "elements": [
+                { "type": "image", "image_url": "https://example.com/a.png", "alt_text": "avatar" },
+                { "type": "plain_text", "text": "2 replies" }
+            ]
+        },
+        {
+            "type": "actions",
+            "elements": [
+                {
+                    "type": "button",
+                    "action_id": "approve",
+                    "text": { "type": "plain_text", "text": "Approve" },
+                    "style": "primary"
+                },
+                {
+                    "type": "button",
+                    "action_id": "docs",
+                    "text": { "type": "plain_text", "text": "Docs" },
+                    "url": "https://example.com/docs"
+                },
+                {
+                    "type": "conversations_select",
+                    "action_id": "where",
+                    "placeholder": { "type": "plain_text", "text": "Pick a channel" }
+                },
+                { "type": "datepicker", "action_id": "when", "initial_date": "2024-03-01" }
+            ]
+        }
+    ]))
+    .unwrap();
+
+    let page = html::render(&blocks);
+
+    assert!(page.starts_with("<!DOCTYPE html>\n<html>"));
+    assert!(page.contains("<style>"));
+    assert!(!page.contains("<script"));
+
+    let body = html::fragment(&blocks);
+    assert!(page.contains(&body));
+    assert_eq!(
+        body,
+        "<div class=\"blocks\">\
+         <div class=\"block header\">Q3 &lt;report&gt;</div>\
+         <div class=\"block section\"><div class=\"section-body\">\
+         <div class=\"text\"><blockquote><s>old</s> <b>new</b> <span class=\"mention\">#ops</span></blockquote>\
+         \n<pre>a &lt; b</pre></div>\
+         <div class=\"fields\">\
+         <div class=\"text\"><b>Owner</b>\n<span class=\"mention\">@SAZ94GDB8</span></div>\
+         <div class=\"text\">&quot;Due&quot; soon</div>\
+         </div></div>\
+         <img class=\"accessory-image\" src=\"https://example.com/chart.png?a=1&amp;b=2\" alt=\"chart\">\
+         </div>\
+         <div class=\"block context\"><img src=\"https://example.com/a.png\" alt=\"avatar\"><span>2 replies</span></div>\
+         <div class=\"block actions\">\
+         <span class=\"button primary\">Approve</span>\
+         <a class=\"button\" href=\"https://example.com/docs\">Docs</a>\
+         <span class=\"select placeholder\">Pick a channel</span>\
+         <span class=\"datepicker\">2024-03-01</span>\
+         </div>\
+         </div>"
+    );
+}
+
+#[test]
+fn renders_modal_inputs_as_html() {
+    let modal: Modal = serde_json::from_value(json!({
+        "type": "modal",
+        "title": { "type": "plain_text", "text": "Report" },
+        "close": { "type": "plain_text", "text": "Nope" },
+        "blocks": [
+            {
+                "type": "input",
+                "label": { "type": "plain_text", "text": "Details" },
+                "optional": true,
+                "element": {
+                    "type": "plain_text_input",
+                    "action_id": "details",
+                    "multiline": true,
+                    "initial_value": "It broke & then <stopped>"
+                },
+                "hint": { "type": "plain_text", "text": "Be specific" }
+            },
+            {
+                "type": "input",
+                "label": { "type": "plain_text", "text": "Severity" },
+                "element": {
+                    "type": "radio_buttons",
+                    "action_id": "severity",
+                    "options": [
+                        { "text": { "type": "mrkdwn", "text": "*High*" }, "value": "high" },
+                        { "text": { "type": "plain_text", "text": "Low" }, "value": "low" }
+                    ],
+                    "initial_option": { "text": { "type": "plain_text", "text": "Low" }, "value": "low" }
+                }
+            }
+        ]
+    }))
+    .unwrap();
+
+    let page = html::modal(&modal);
+
+    assert!(page.contains("<div class=\"modal-title\">Report</div>"));
+    assert!(page.contains(
+        "<div class=\"block input\"><label class=\"label\">Details \
+         <span class=\"optional\">(optional)</span></label>\
+         <textarea rows=\"3\" disabled>It broke &amp; then &lt;stopped&gt;</textarea>\
+         <div class=\"hint\">Be specific</div></div>"
+    ));
+    assert!(page.contains(
+        "<div class=\"choices\">\
+         <label><input type=\"radio\" disabled> <b>High</b></label>\
+         <label><input type=\"radio\" disabled checked> Low</label>\
+         </div>"
+    ));
+    assert!(page.contains("<div class=\"modal-buttons\"><span class=\"button\">Nope</span></div>"));
+}
+
+#[test]
+fn keeps_only_safe_links_in_html() {
+    assert_eq!(
+        html::from_mrkdwn(
+            "<javascript:alert(document.cookie)|click> <JAVASCRIPT:x> \
+             <!date^1392734382^{date}^data:text/html,x|Feb 18> \
+             <HTTPS://example.com|ok> <mailto:bob@example.com|mail>"
+        ),
+        "click JAVASCRIPT:x Feb 18 \
+         <a href=\"HTTPS://example.com\">ok</a> <a href=\"mailto:bob@example.com\">mail</a>"
+    );
+
+    let blocks: Vec<MessageBlock> = serde_json::from_value(json!([{
+        "type": "actions",
+        "elements": [
+            {
+                "type": "button",
+                "action_id": "bad",
+                "text": { "type": "plain_text", "text": "Bad" },
+                "url": "javascript:alert(1)"
+            },
+            {
+                "type": "button",
+                "action_id": "good",
+                "text": { "type": "plain_text", "text": "Good" },
+                "url": "https://example.com"
+            }
+        ]
+    }]))
+    .unwrap();
+
+    let body = html::fragment(&blocks);
+    assert!(!body.contains("javascript"));
+    assert!(body.contains("<span class=\"button\">Bad</span>"));
+    assert!(body.contains("<a class=\"button\" href=\"https://example.com\">Good</a>"));
+}
+
+#[test]
+fn renders_fields_in_columns_for_terminal() {
+    let blocks: Vec<MessageBlock> = serde_json::from_value(json!([
+        {
+            "type": "section",
+            "text": { "type": "mrkdwn", "text": "> Build *failed*\n> on <#C024BE7LR|ci>" },
+            "fields": [
+                { "type": "mrkdwn", "text": "*Branch*\n`main`" },
+                { "type": "mrkdwn", "text": "*Author*\n<@U024BE7LH|alice>" },
+                { "type": "plain_text", "text": "Took 4m" }
+            ]
+        },
+        {
+            "type": "context",
+            "elements": [{ "type": "mrkdwn", "text": "Triggered by *push*" }]
+        },
+        {
+            "type": "actions",
+            "elements": [
+                {
+                    "type": "button",
+                    "action_id": "retry",
+                    "text": { "type": "plain_text", "text": "Retry" },
+                    "style": "primary"
+                },
+                {
+                    "type": "button",
+                    "action_id": "logs",
+                    "text": { "type": "plain_text", "text": "Logs" }
+                },
+                {
+                    "type": "static_select",
+                    "action_id": "assign",
+                    "placeholder": { "type": "plain_text", "text": "Assign" }
+                }
+            ]
+        }
+    ]))
+    .unwrap();
+
+    assert_eq!(
+        Terminal::new().width(30).colors(false).render(&blocks),
+        "\u{2502} Build failed\n\
+         \u{2502} on #ci\n\
+         Branch          Author\n\
+         main            @alice\n\
+         Took 4m\n\
+         Triggered by push\n\
+         [Retry] [Logs] [Assign \u{25be}]"
+    );
+
+    assert_eq!(
+        Terminal::new().width(30).render(&blocks),
+        "\x1b[2m\u{2502} \x1b[0mBuild \x1b[1mfailed\x1b[0m\n\
+         \x1b[2m\u{2502} \x1b[0mon \x1b[34m#ci\x1b[0m\n\
+         \x1b[1mBranch\x1b[0m          \x1b[1mAuthor\x1b[0m\n\
+         \x1b[36mmain\x1b[0m            \x1b[34m@alice\x1b[0m\n\
+         Took 4m\n\
+         \x1b[2mTriggered by \x1b[1mpush\x1b[0m\x1b[2m\x1b[0m\n\
+         \x1b[32m[Retry]\x1b[0m [Logs] [\x1b[2mAssign\x1b[0m \u{25be}]"
+    );
+}
+
+#[test]
+fn strips_control_characters_for_terminal() {
+    let blocks: Vec<MessageBlock> = serde_json::from_value(json!([
+        { "type": "header", "text": { "type": "plain_text", "text": "Deploy\x1b[2J\r done\x07" } },
+        {
+            "type": "section",
+            "text": { "type": "mrkdwn", "text": "*\x1b[31mred*\n\u{9b}0mplain\tand tabbed" },
+            "fields": [{ "type": "plain_text", "text": "\x1b]0;title\x07Took 4m" }],
+            "accessory": {
+                "type": "button",
+                "action_id": "retry",
+                "text": { "type": "plain_text", "text": "Re\x08try" }
+            }
+        }
+    ]))
+    .unwrap();
+
+    assert_eq!(
+        Terminal::new().width(30).render(&blocks),
+        "\x1b[1mDeploy[2J done\x1b[0m\n\
+         \x1b[1m[31mred\x1b[0m\n\
+         0mplain and tabbed\n\
+         ]0;titleTook 4m\n\
+         [Retry]"
+    );
+}
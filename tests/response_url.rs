@@ -0,0 +1,83 @@
+mod common;
+
+use common::{CannedResponse, StubServer};
+use serde_json::json;
+use slack_lib::response_url::*;
+use slack_lib::slash_commands::{ResponseType, SlashCommandResponse};
+use std::time::{Duration, SystemTime};
+
+#[tokio::test]
+async fn posts_json_and_counts_uses() {
+    let server = StubServer::start(
+        (0..MAX_USES)
+            .map(|_| CannedResponse::json(json!({ "ok": true })))
+            .collect(),
+    )
+    .await;
+    let response_url = ResponseUrl::new(format!("{}commands/T1/1/abc", server.url));
+
+    let message = SlashCommandResponse::builder()
+        .response_type(ResponseType::InChannel)
+        .content("Deploy finished")
+        .thread_ts("1548261231.000200")
+        .build();
+    for _ in 0..MAX_USES {
+        response_url.send(&message).await.unwrap();
+    }
+
+    assert_eq!(response_url.remaining_uses(), 0);
+    assert!(matches!(
+        response_url.send(&message).await,
+        Err(Error::UsesExhausted)
+    ));
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), MAX_USES as usize);
+    assert_eq!(requests[0].path, "/commands/T1/1/abc");
+    assert_eq!(
+        requests[0].json(),
+        json!({
+            "response_type": "in_channel",
+            "text": "Deploy finished",
+            "thread_ts": "1548261231.000200"
+        })
+    );
+}
+
+#[tokio::test]
+async fn refuses_expired_urls_without_sending() {
+    let server = StubServer::start(vec![]).await;
+    let response_url = ResponseUrl::new(server.url.clone())
+        .issued_at(SystemTime::now() - LIFETIME - Duration::from_secs(1));
+
+    assert!(matches!(
+        response_url.delete_original().await,
+        Err(Error::Expired)
+    ));
+    assert_eq!(response_url.remaining_uses(), MAX_USES);
+    assert!(server.requests().is_empty());
+}
+
+#[tokio::test]
+async fn surfaces_slack_errors() {
+    let server = StubServer::start(vec![
+        CannedResponse::text(404, "expired_url"),
+        CannedResponse::json(json!({ "ok": false, "error": "no_text" })),
+    ])
+    .await;
+    let response_url = ResponseUrl::new(server.url.clone());
+    let message = SlashCommandResponse::builder().build();
+
+    match response_url.send(&message).await {
+        Err(Error::Api { status, error }) => {
+            assert_eq!(status, 404);
+            assert_eq!(error, "expired_url");
+        }
+        other => panic!("expected an api error, got {:?}", other),
+    }
+    match response_url.send(&message).await {
+        Err(Error::Api { error, .. }) => assert_eq!(error, "no_text"),
+        other => panic!("expected an api error, got {:?}", other),
+    }
+    assert_eq!(response_url.remaining_uses(), MAX_USES - 2);
+}
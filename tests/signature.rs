@@ -0,0 +1,18 @@
+use slack_lib::signature::*;
+use std::time::{Duration, UNIX_EPOCH};
+
+#[test]
+fn rejects_extreme_timestamps_as_expired() {
+    let verifier = SignatureVerifier::new("secret");
+    let now = UNIX_EPOCH + Duration::from_secs(1531420618);
+
+    for timestamp in [i64::MIN, i64::MAX] {
+        let timestamp = timestamp.to_string();
+        let signature = verifier.sign(&timestamp, b"payload");
+
+        assert!(matches!(
+            verifier.verify_at(&timestamp, &signature, b"payload", now),
+            Err(Error::Expired { .. })
+        ));
+    }
+}
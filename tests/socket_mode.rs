@@ -0,0 +1,245 @@
+mod common;
+
+use common::{CannedResponse, StubServer};
+use futures_util::{SinkExt, StreamExt};
+use serde_json::json;
+use slack_lib::events::Event;
+use slack_lib::socket_mode::*;
+use slack_lib::web_api::{self, Client};
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio_tungstenite::tungstenite::Message;
+
+type ServerSocket = tokio_tungstenite::WebSocketStream<tokio::net::TcpStream>;
+
+async fn accept(listener: &TcpListener) -> ServerSocket {
+    let (stream, _) = listener.accept().await.unwrap();
+    tokio_tungstenite::accept_async(stream).await.unwrap()
+}
+
+async fn send(socket: &mut ServerSocket, message: serde_json::Value) {
+    socket
+        .send(Message::text(message.to_string()))
+        .await
+        .unwrap();
+}
+
+/// The next text message from the client, skipping anything else
+async fn receive(socket: &mut ServerSocket) -> serde_json::Value {
+    loop {
+        match socket.next().await.unwrap().unwrap() {
+            Message::Text(text) => return serde_json::from_str(text.as_str()).unwrap(),
+            _ => continue,
+        }
+    }
+}
+
+fn hello() -> serde_json::Value {
+    json!({
+        "type": "hello",
+        "num_connections": 1,
+        "debug_info": { "host": "applink-7fc4fdbb64-4x5xq" },
+        "connection_info": { "app_id": "A111" }
+    })
+}
+
+#[tokio::test]
+async fn acks_requests_and_reconnects_when_asked() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let socket_url = format!("ws://{}/link", listener.local_addr().unwrap());
+
+    let acks = tokio::spawn(async move {
+        let mut first = accept(&listener).await;
+        send(&mut first, hello()).await;
+        send(
+            &mut first,
+            json!({
+                "type": "events_api",
+                "envelope_id": "dbdd0ef3-1543-4f94-bfb4-133d0e6c1545",
+                "accepts_response_payload": false,
+                "retry_attempt": 1,
+                "retry_reason": "timeout",
+                "payload": {
+                    "type": "event_callback",
+                    "team_id": "T111",
+                    "api_app_id": "A111",
+                    "event_id": "Ev111",
+                    "event_time": 1610000000,
+                    "event": {
+                        "type": "app_mention",
+                        "user": "U111",
+                        "text": "<@U222> hi",
+                        "ts": "1610000000.000100",
+                        "channel": "C111"
+                    }
+                }
+            }),
+        )
+        .await;
+        let first_ack = receive(&mut first).await;
+        send(
+            &mut first,
+            json!({ "type": "disconnect", "reason": "refresh_requested" }),
+        )
+        .await;
+
+        let mut second = accept(&listener).await;
+        send(&mut second, hello()).await;
+        send(
+            &mut second,
+            json!({
+                "type": "slash_commands",
+                "envelope_id": "5f5c8b8e-2d2b-4c57-a0d1-2a3e1a8f3ad2",
+                "accepts_response_payload": true,
+                "payload": {
+                    "token": "xxx",
+                    "team_id": "T111",
+                    "channel_id": "C111",
+                    "user_id": "U111",
+                    "command": "/deploy",
+                    "text": "main",
+                    "is_enterprise_install": "false",
+                    "response_url": "https://hooks.slack.com/commands/T111/1/abc",
+                    "trigger_id": "111.222.abc"
+                }
+            }),
+        )
+        .await;
+        let second_ack = receive(&mut second).await;
+        send(
+            &mut second,
+            json!({ "type": "disconnect", "reason": "link_disabled" }),
+        )
+        .await;
+
+        (first_ack, second_ack)
+    });
+
+    let server = StubServer::start(vec![
+        // The first attempt fails, and is retried after backing off
+        CannedResponse::text(500, "upstream connect error"),
+        CannedResponse::json(json!({ "ok": true, "url": socket_url })),
+        CannedResponse::json(json!({ "ok": true, "url": socket_url })),
+    ])
+    .await;
+    let client = SocketModeClient::with_client(Client::new("xapp-token").base_url(&server.url))
+        .backoff(Duration::from_millis(10), Duration::from_millis(50));
+
+    let mut connection = client.connect().await.unwrap();
+
+    let request = connection.next().await.unwrap().unwrap();
+    assert_eq!(connection.hello().unwrap().num_connections, 1);
+    assert_eq!(request.retry_attempt, 1);
+    assert_eq!(request.retry_reason.as_deref(), Some("timeout"));
+    match &request.payload {
+        RequestPayload::EventsApi(callback) => {
+            assert!(matches!(callback.event, Event::AppMention(_)))
+        }
+        other => panic!("expected events_api, got {:?}", other),
+    }
+    connection.ack(&request.envelope_id, None).await.unwrap();
+
+    let request = connection.next().await.unwrap().unwrap();
+    assert!(request.accepts_response_payload);
+    match &request.payload {
+        RequestPayload::SlashCommands(command) => assert_eq!(command.command, "/deploy"),
+        other => panic!("expected slash_commands, got {:?}", other),
+    }
+    connection
+        .ack(
+            &request.envelope_id,
+            Some(json!({ "text": "Deploying main" })),
+        )
+        .await
+        .unwrap();
+
+    assert!(connection.next().await.unwrap().is_none());
+
+    let (first_ack, second_ack) = acks.await.unwrap();
+    assert_eq!(
+        first_ack,
+        json!({ "envelope_id": "dbdd0ef3-1543-4f94-bfb4-133d0e6c1545" })
+    );
+    assert_eq!(
+        second_ack,
+        json!({
+            "envelope_id": "5f5c8b8e-2d2b-4c57-a0d1-2a3e1a8f3ad2",
+            "payload": { "text": "Deploying main" }
+        })
+    );
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[0].path, "/apps.connections.open");
+    assert_eq!(requests[0].headers["authorization"], "Bearer xapp-token");
+}
+
+#[tokio::test]
+async fn gives_up_on_api_errors() {
+    let server = StubServer::start(vec![CannedResponse::json(json!({
+        "ok": false,
+        "error": "invalid_auth"
+    }))])
+    .await;
+    let client = SocketModeClient::with_client(Client::new("xoxb-wrong").base_url(&server.url));
+
+    match client.connect().await {
+        Err(Error::WebApi(web_api::Error::Api { error, .. })) => assert_eq!(error, "invalid_auth"),
+        other => panic!("expected invalid_auth, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[tokio::test]
+async fn hands_back_envelope_id_for_unparseable_payloads() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let socket_url = format!("ws://{}/link", listener.local_addr().unwrap());
+
+    let acks = tokio::spawn(async move {
+        let mut socket = accept(&listener).await;
+        send(&mut socket, hello()).await;
+        send(
+            &mut socket,
+            json!({
+                "type": "interactive",
+                "envelope_id": "0b2a7f0e-8c4d-4a8b-9f5e-3c1d2e4f6a7b",
+                "accepts_response_payload": true,
+                // No `user` or `view`
+                "payload": { "type": "view_submission", "api_app_id": "A111" }
+            }),
+        )
+        .await;
+        let ack = receive(&mut socket).await;
+        send(
+            &mut socket,
+            json!({ "type": "disconnect", "reason": "link_disabled" }),
+        )
+        .await;
+        ack
+    });
+
+    let server = StubServer::start(vec![CannedResponse::json(
+        json!({ "ok": true, "url": socket_url }),
+    )])
+    .await;
+    let client = SocketModeClient::with_client(Client::new("xapp-token").base_url(&server.url));
+    let mut connection = client.connect().await.unwrap();
+
+    match connection.next().await {
+        Err(Error::Payload {
+            envelope_id,
+            message,
+            ..
+        }) => {
+            assert_eq!(envelope_id, "0b2a7f0e-8c4d-4a8b-9f5e-3c1d2e4f6a7b");
+            assert_eq!(message["payload"]["type"], "view_submission");
+            connection.ack(&envelope_id, None).await.unwrap();
+        }
+        other => panic!("expected a payload error, got {:?}", other.map(|_| ())),
+    }
+
+    assert!(connection.next().await.unwrap().is_none());
+    assert_eq!(
+        acks.await.unwrap(),
+        json!({ "envelope_id": "0b2a7f0e-8c4d-4a8b-9f5e-3c1d2e4f6a7b" })
+    );
+}
@@ -0,0 +1,175 @@
+mod common;
+
+use common::{CannedResponse, StubServer};
+use serde_json::json;
+use slack_lib::web_api::conversations::ConversationType;
+use slack_lib::web_api::users::*;
+use slack_lib::web_api::{Client, Error};
+
+#[tokio::test]
+async fn sends_form_encoded_arguments_with_the_token() {
+    let server = StubServer::start(vec![CannedResponse::json(json!({
+        "ok": true,
+        "channels": [{ "id": "C012AB3CD", "name": "general", "is_channel": true }],
+        "response_metadata": { "next_cursor": "" }
+    }))])
+    .await;
+    let client = Client::new("xoxb-token").base_url(&server.url);
+
+    let response = client
+        .call(
+            &UsersConversations::builder()
+                .user("W012A3CDE")
+                .types(vec![
+                    ConversationType::PublicChannel,
+                    ConversationType::PrivateChannel,
+                ])
+                .limit(100)
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.channels[0].name.as_deref(), Some("general"));
+    assert_eq!(response.response_metadata.next_cursor(), None);
+
+    let request = &server.requests()[0];
+    assert_eq!(request.path, "/users.conversations");
+    assert_eq!(request.headers["authorization"], "Bearer xoxb-token");
+    let form = request.form();
+    assert_eq!(form["user"], "W012A3CDE");
+    assert_eq!(form["types"], "public_channel,private_channel");
+    assert_eq!(form["limit"], "100");
+}
+
+#[tokio::test]
+async fn json_encodes_nested_arguments() {
+    let server = StubServer::start(vec![CannedResponse::json(json!({
+        "ok": true,
+        "profile": { "status_text": "riding a train" }
+    }))])
+    .await;
+    let client = Client::new("xoxp-token").base_url(&server.url);
+
+    let profile = UserProfile {
+        status_text: Some(String::from("riding a train")),
+        ..Default::default()
+    };
+    let response = client
+        .call(&UsersProfileSet::builder().profile(profile).build())
+        .await
+        .unwrap();
+
+    assert_eq!(
+        response.profile.status_text.as_deref(),
+        Some("riding a train")
+    );
+    let form = server.requests()[0].form();
+    assert_eq!(form["profile"], r#"{"status_text":"riding a train"}"#);
+}
+
+#[tokio::test]
+async fn surfaces_slack_errors() {
+    let server = StubServer::start(vec![CannedResponse::json(json!({
+        "ok": false,
+        "error": "users_not_found"
+    }))])
+    .await;
+    let client = Client::new("xoxb-token").base_url(&server.url);
+
+    let error = client
+        .call(
+            &UsersLookupByEmail::builder()
+                .email("nobody@example.com")
+                .build(),
+        )
+        .await
+        .unwrap_err();
+
+    match error {
+        Error::Api { error, .. } => assert_eq!(error, "users_not_found"),
+        other => panic!("unexpected error {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn surfaces_rate_limits() {
+    let server = StubServer::start(vec![
+        CannedResponse::text(429, "").header("retry-after", "30")
+    ])
+    .await;
+    let client = Client::new("xoxb-token").base_url(&server.url);
+
+    let error = client
+        .call(&UsersList::builder().build())
+        .await
+        .unwrap_err();
+
+    match error {
+        Error::RateLimited { retry_after } => assert_eq!(retry_after, Some(30)),
+        other => panic!("unexpected error {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn publishes_home_tabs_and_returns_the_rendered_view() {
+    use slack_lib::block_kit::blocks::Header;
+    use slack_lib::block_kit::views::{HomeTab, View};
+    use slack_lib::web_api::views::ViewsPublish;
+
+    let server = StubServer::start(vec![CannedResponse::json(json!({
+        "ok": true,
+        "view": {
+            "id": "VMHU10V25",
+            "team_id": "T8N4K1JN",
+            "type": "home",
+            "blocks": [
+                {
+                    "type": "header",
+                    "block_id": "2Sq",
+                    "text": { "type": "plain_text", "text": "Welcome home", "emoji": true }
+                }
+            ],
+            "private_metadata": "",
+            "callback_id": "",
+            "state": { "values": {} },
+            "hash": "1580236556.1eWRBGh4",
+            "root_view_id": "VMHU10V25",
+            "app_id": "AA4928AQ",
+            "bot_id": "BA13894H"
+        }
+    }))])
+    .await;
+    let client = Client::new("xoxb-token").base_url(&server.url);
+
+    let response = client
+        .call(
+            &ViewsPublish::builder()
+                .user_id("U061F7AUR")
+                .view(
+                    HomeTab::builder()
+                        .blocks(vec![Header::builder().text("Welcome home").build().into()])
+                        .build(),
+                )
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.view.id, "VMHU10V25");
+    assert_eq!(response.view.hash, "1580236556.1eWRBGh4");
+    assert!(matches!(response.view.view, View::HomeTab(_)));
+
+    let form = server.requests()[0].form();
+    assert_eq!(form["user_id"], "U061F7AUR");
+    let view: serde_json::Value = serde_json::from_str(&form["view"]).unwrap();
+    assert_eq!(
+        view,
+        json!({
+            "type": "home",
+            "blocks": [
+                { "type": "header", "text": { "type": "plain_text", "text": "Welcome home" } }
+            ]
+        })
+    );
+}